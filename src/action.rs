@@ -0,0 +1,302 @@
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::input::InputSystem;
+use bevy::prelude::{
+    App, Axis, ButtonInput, Condition, FixedPreUpdate, GamepadAxis, GamepadAxisType, GamepadButton,
+    GamepadButtonType, Gamepads, IntoSystemConfigs, KeyCode, MouseButton, Plugin, PreUpdate, Res,
+    ResMut, Resource, resource_changed, resource_exists, Startup, Update, warn,
+};
+use bevy::utils::{HashMap, HashSet};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+pub struct ActionPlugin;
+
+impl Plugin for ActionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputBindings>()
+            .init_resource::<ActionState>()
+            .add_systems(
+                Startup,
+                load_bindings.run_if(resource_exists::<BindingsFile>),
+            )
+            // Presses are caught every frame, so taps between two ticks are not lost.
+            .add_systems(PreUpdate, buffer_presses.after(InputSystem))
+            // Sampled once per simulation tick so `just_pressed` holds for exactly one tick.
+            .add_systems(FixedPreUpdate, update_action_state)
+            .add_systems(
+                Update,
+                save_bindings.run_if(
+                    resource_exists::<BindingsFile>.and_then(resource_changed::<InputBindings>),
+                ),
+            );
+    }
+}
+
+/// Remembers button presses until the next simulation tick, which may be several frames away
+/// during a hit-stop.
+fn buffer_presses(
+    mut action_state: ResMut<ActionState>,
+    bindings: Res<InputBindings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    gamepads: Res<Gamepads>,
+) {
+    for binding in bindings.bindings.iter() {
+        let just_pressed = match binding.source {
+            InputSource::Key(key) => keyboard_input.just_pressed(key),
+            InputSource::Mouse(button) => mouse_input.just_pressed(button),
+            InputSource::GamepadButton(button_type) => gamepads.iter().any(|gamepad| {
+                gamepad_buttons.just_pressed(GamepadButton::new(gamepad, button_type))
+            }),
+            InputSource::GamepadAxis(_) => false,
+        };
+        if just_pressed {
+            action_state.buffered.insert(binding.action, binding.scale);
+        }
+    }
+}
+
+fn update_action_state(
+    mut action_state: ResMut<ActionState>,
+    bindings: Res<InputBindings>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+) {
+    let ActionState {
+        current,
+        previous,
+        buffered,
+        just_pressed,
+    } = &mut *action_state;
+    std::mem::swap(current, previous);
+    current.clear();
+
+    for binding in bindings.bindings.iter() {
+        let value = match binding.source {
+            InputSource::Key(key) => button_value(keyboard_input.pressed(key)),
+            InputSource::Mouse(button) => button_value(mouse_input.pressed(button)),
+            InputSource::GamepadButton(button_type) => {
+                button_value(gamepads.iter().any(|gamepad| {
+                    gamepad_buttons.pressed(GamepadButton::new(gamepad, button_type))
+                }))
+            }
+            InputSource::GamepadAxis(axis_type) => gamepads
+                .iter()
+                .filter_map(|gamepad| gamepad_axes.get(GamepadAxis::new(gamepad, axis_type)))
+                .map(|value| apply_deadzone(value, bindings.deadzone))
+                .sum(),
+        };
+        *current.entry(binding.action).or_default() += value * binding.scale;
+    }
+
+    // A buffered tap that was already released still counts as pressed for this one tick.
+    for (action, value) in buffered.iter() {
+        let current_value = current.entry(*action).or_default();
+        if *current_value == 0.0 {
+            *current_value = *value;
+        }
+    }
+
+    for value in current.values_mut() {
+        *value = value.clamp(-1.0, 1.0);
+    }
+
+    just_pressed.clear();
+    just_pressed.extend(buffered.drain().map(|(action, _)| action));
+    just_pressed.extend(current.iter().filter_map(|(action, value)| {
+        let was_released = previous.get(action).copied().unwrap_or_default() == 0.0;
+        (*value != 0.0 && was_released).then_some(*action)
+    }));
+}
+
+fn button_value(pressed: bool) -> f32 {
+    if pressed {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Zeroes `value` inside the deadzone and rescales the rest so output still starts at zero.
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    if value.abs() <= deadzone {
+        return 0.0;
+    }
+    (value.abs() - deadzone) / (1.0 - deadzone) * value.signum()
+}
+
+fn load_bindings(file: Res<BindingsFile>, mut bindings: ResMut<InputBindings>) {
+    let Ok(contents) = fs::read_to_string(&file.0) else {
+        return;
+    };
+    match ron::from_str::<InputBindings>(&contents) {
+        Ok(mut loaded) => {
+            // A deadzone of 1.0 divides by zero in `apply_deadzone`, and above it the axis inverts.
+            if !(0.0..MAX_DEADZONE).contains(&loaded.deadzone) {
+                let deadzone = if loaded.deadzone.is_nan() {
+                    InputBindings::default().deadzone
+                } else {
+                    loaded.deadzone.clamp(0.0, MAX_DEADZONE)
+                };
+                warn!(
+                    "Deadzone {} in {:?} is outside 0.0..{}, using {}",
+                    loaded.deadzone, file.0, MAX_DEADZONE, deadzone
+                );
+                loaded.deadzone = deadzone;
+            }
+            *bindings = loaded;
+        }
+        Err(err) => warn!("Ignoring unreadable input bindings {:?}: {}", file.0, err),
+    }
+}
+
+fn save_bindings(file: Res<BindingsFile>, bindings: Res<InputBindings>) {
+    let contents = match ron::ser::to_string_pretty(&*bindings, PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("Could not serialize input bindings: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = fs::write(&file.0, contents) {
+        warn!("Could not save input bindings to {:?}: {}", file.0, err);
+    }
+}
+
+/// Something the player can do, independent of the device that triggers it.
+///
+/// `Thrust`, `Turn` and `Roll` are axes in `-1.0..=1.0`; the rest behave like buttons.
+#[derive(Serialize, Deserialize, Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum Action {
+    Thrust,
+    Turn,
+    Roll,
+    Fire,
+    Shield,
+    Pause,
+    SelectSingleShot,
+    SelectSpread,
+    SelectRapid,
+    CycleCamera,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum InputSource {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    GamepadButton(GamepadButtonType),
+    GamepadAxis(GamepadAxisType),
+}
+
+/// Maps one input to an action. `scale` flips or weakens the input, e.g. `-1.0` turns right.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Binding {
+    pub action: Action,
+    pub source: InputSource,
+    #[serde(default = "default_scale")]
+    pub scale: f32,
+}
+
+impl Binding {
+    pub fn new(action: Action, source: InputSource) -> Self {
+        Self {
+            action,
+            source,
+            scale: default_scale(),
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
+
+fn default_scale() -> f32 {
+    1.0
+}
+
+/// All bindings, persisted to [`BindingsFile`] when it exists.
+#[derive(Resource, Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct InputBindings {
+    /// Gamepad axis values at or below this magnitude are ignored.
+    pub deadzone: f32,
+    pub bindings: Vec<Binding>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        use Action::*;
+        use InputSource::{GamepadAxis, GamepadButton, Key, Mouse};
+
+        Self {
+            deadzone: 0.15,
+            bindings: vec![
+                Binding::new(Thrust, Key(KeyCode::KeyW)),
+                Binding::new(Thrust, Key(KeyCode::KeyS)).with_scale(-1.0),
+                Binding::new(Thrust, GamepadAxis(GamepadAxisType::LeftStickY)),
+                Binding::new(Turn, Key(KeyCode::KeyA)),
+                Binding::new(Turn, Key(KeyCode::KeyD)).with_scale(-1.0),
+                Binding::new(Turn, GamepadAxis(GamepadAxisType::LeftStickX)).with_scale(-1.0),
+                Binding::new(Roll, Key(KeyCode::KeyQ)).with_scale(-1.0),
+                Binding::new(Roll, Key(KeyCode::KeyE)),
+                Binding::new(Roll, GamepadButton(GamepadButtonType::LeftTrigger)).with_scale(-1.0),
+                Binding::new(Roll, GamepadButton(GamepadButtonType::RightTrigger)),
+                Binding::new(Fire, Key(KeyCode::Space)),
+                Binding::new(Fire, Mouse(MouseButton::Left)),
+                Binding::new(Fire, GamepadButton(GamepadButtonType::RightTrigger2)),
+                Binding::new(Fire, GamepadButton(GamepadButtonType::South)),
+                Binding::new(Shield, Key(KeyCode::Tab)),
+                Binding::new(Shield, Mouse(MouseButton::Right)),
+                Binding::new(Shield, GamepadButton(GamepadButtonType::LeftTrigger2)),
+                Binding::new(Pause, Key(KeyCode::KeyP)),
+                Binding::new(Pause, GamepadButton(GamepadButtonType::Start)),
+                Binding::new(SelectSingleShot, Key(KeyCode::Digit1)),
+                Binding::new(SelectSingleShot, GamepadButton(GamepadButtonType::DPadLeft)),
+                Binding::new(SelectSpread, Key(KeyCode::Digit2)),
+                Binding::new(SelectSpread, GamepadButton(GamepadButtonType::DPadUp)),
+                Binding::new(SelectRapid, Key(KeyCode::Digit3)),
+                Binding::new(SelectRapid, GamepadButton(GamepadButtonType::DPadRight)),
+                Binding::new(CycleCamera, Key(KeyCode::KeyC)),
+                Binding::new(CycleCamera, GamepadButton(GamepadButtonType::Select)),
+            ],
+        }
+    }
+}
+
+/// Where [`InputBindings`] are persisted. Without it, the default bindings are used.
+#[derive(Resource, Debug)]
+pub struct BindingsFile(pub PathBuf);
+
+/// Per-frame action values, combined from every bound input.
+#[derive(Resource, Debug, Default)]
+pub struct ActionState {
+    current: HashMap<Action, f32>,
+    previous: HashMap<Action, f32>,
+    /// Button presses seen since the last tick, with the scale of the binding that fired.
+    buffered: HashMap<Action, f32>,
+    just_pressed: HashSet<Action>,
+}
+
+impl ActionState {
+    pub fn value(&self, action: Action) -> f32 {
+        self.current.get(&action).copied().unwrap_or_default()
+    }
+
+    pub fn pressed(&self, action: Action) -> bool {
+        self.value(action) != 0.0
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(&action)
+    }
+}
+
+/// Largest deadzone accepted from the bindings file; leaves a sliver of travel to rescale.
+const MAX_DEADZONE: f32 = 0.95;
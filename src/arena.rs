@@ -0,0 +1,177 @@
+//! Keeps the play area on screen: every entity with a [`BoundsPolicy`] is wrapped, bounced,
+//! clamped or despawned when it leaves [`ArenaBounds`].
+
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::{
+    App, Camera, Changed, Commands, Component, DespawnRecursiveExt, Entity, FixedUpdate,
+    IntoSystemConfigs, Plugin, Projection, Query, Res, ResMut, Resource, resource_changed,
+    Transform, Update, Vec2, With,
+};
+use serde::Deserialize;
+
+use crate::camera::CAMERA_DISTANCE;
+use crate::collision_detection::FastMover;
+use crate::config::GameConfig;
+use crate::interpolation::TransformInterpolation;
+use crate::movement::Velocity;
+use crate::schedule::InGameSet;
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ArenaBounds>()
+            .add_systems(
+                Update,
+                (
+                    apply_arena_config.run_if(resource_changed::<GameConfig>),
+                    fit_bounds_to_camera,
+                ),
+            )
+            .add_systems(
+                FixedUpdate,
+                apply_bounds_policies.in_set(InGameSet::DespawnEntities),
+            );
+    }
+}
+
+fn apply_arena_config(config: Res<GameConfig>, mut bounds: ResMut<ArenaBounds>) {
+    bounds.margin = config.arena.margin;
+}
+
+fn fit_bounds_to_camera(
+    query: Query<&Projection, (With<Camera>, Changed<Projection>)>,
+    mut bounds: ResMut<ArenaBounds>,
+) {
+    for projection in query.iter() {
+        if let Projection::Perspective(perspective) = projection {
+            bounds.half_extents = ArenaBounds::visible_half_extents(
+                CAMERA_DISTANCE,
+                perspective.fov,
+                perspective.aspect_ratio,
+            );
+        }
+    }
+}
+
+fn apply_bounds_policies(
+    mut commands: Commands,
+    mut query: Query<(Entity, &BoundsPolicy, &mut Transform, Option<&mut Velocity>)>,
+    mut fast_mover_query: Query<&mut FastMover>,
+    mut interpolation_query: Query<&mut TransformInterpolation>,
+    bounds: Res<ArenaBounds>,
+) {
+    let outer = bounds.half_extents + Vec2::splat(bounds.margin);
+    for (entity, policy, mut transform, velocity) in query.iter_mut() {
+        let position = Vec2::new(transform.translation.x, transform.translation.z);
+        match policy {
+            BoundsPolicy::Wrap => {
+                let wrapped = Vec2::new(wrap(position.x, outer.x), wrap(position.y, outer.y));
+                if wrapped == position {
+                    continue;
+                }
+                transform.translation.x = wrapped.x;
+                transform.translation.z = wrapped.y;
+                // A jump across the arena is not motion: don't sweep or blend along it.
+                if let Ok(mut fast_mover) = fast_mover_query.get_mut(entity) {
+                    fast_mover.reset();
+                }
+                if let Ok(mut interpolation) = interpolation_query.get_mut(entity) {
+                    interpolation.teleport();
+                }
+            }
+            BoundsPolicy::Bounce | BoundsPolicy::Clamp => {
+                let clamped = position.clamp(-bounds.half_extents, bounds.half_extents);
+                if clamped == position {
+                    continue;
+                }
+                transform.translation.x = clamped.x;
+                transform.translation.z = clamped.y;
+                let Some(mut velocity) = velocity else {
+                    continue;
+                };
+                // Only velocity still heading out of the arena is affected.
+                if position.x != clamped.x && 0.0 < velocity.value.x * position.x {
+                    velocity.value.x = policy.velocity_at_edge(velocity.value.x);
+                }
+                if position.y != clamped.y && 0.0 < velocity.value.z * position.y {
+                    velocity.value.z = policy.velocity_at_edge(velocity.value.z);
+                }
+            }
+            BoundsPolicy::Despawn => {
+                if position.abs().cmpgt(outer).any() {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+        }
+    }
+}
+
+/// Moves `value` to the opposite edge once it is past `-half_extent..=half_extent`.
+fn wrap(value: f32, half_extent: f32) -> f32 {
+    if value < -half_extent || half_extent < value {
+        (value + half_extent).rem_euclid(2.0 * half_extent) - half_extent
+    } else {
+        value
+    }
+}
+
+/// What happens to an entity that leaves [`ArenaBounds`].
+#[derive(Component, Deserialize, Clone, Copy, Eq, PartialEq, Debug)]
+pub enum BoundsPolicy {
+    /// Reappears at the opposite edge once fully past the margin.
+    Wrap,
+    /// Stops at the edge and reflects its velocity.
+    Bounce,
+    /// Stops at the edge, dropping the outward part of its velocity.
+    Clamp,
+    /// Despawned once fully past the margin.
+    Despawn,
+}
+
+impl BoundsPolicy {
+    fn velocity_at_edge(self, outward: f32) -> f32 {
+        match self {
+            BoundsPolicy::Bounce => -outward,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Visible play area on the XZ plane, centered on the origin.
+#[derive(Resource, Debug)]
+pub struct ArenaBounds {
+    /// Half the visible width (x) and depth (z).
+    pub half_extents: Vec2,
+    /// Distance past the visible edge before wrapping or despawning, so models leave the screen
+    /// completely first.
+    pub margin: f32,
+}
+
+impl ArenaBounds {
+    /// Half extents of the XZ plane seen by a perspective camera looking straight down from
+    /// `distance`.
+    pub fn visible_half_extents(distance: f32, fov: f32, aspect_ratio: f32) -> Vec2 {
+        let half_depth = distance * (fov / 2.0).tan();
+        Vec2::new(half_depth * aspect_ratio, half_depth)
+    }
+}
+
+impl Default for ArenaBounds {
+    /// Matches the default camera in a default-sized window, for when no camera is available.
+    fn default() -> Self {
+        Self {
+            half_extents: Self::visible_half_extents(
+                CAMERA_DISTANCE,
+                DEFAULT_FOV,
+                DEFAULT_ASPECT_RATIO,
+            ),
+            margin: DEFAULT_MARGIN,
+        }
+    }
+}
+
+const DEFAULT_FOV: f32 = FRAC_PI_4;
+const DEFAULT_ASPECT_RATIO: f32 = 16.0 / 9.0;
+const DEFAULT_MARGIN: f32 = 5.0;
@@ -1,4 +1,13 @@
-use bevy::prelude::{App, AssetServer, Handle, Plugin, Res, ResMut, Resource, Scene, Startup};
+use bevy::asset::{
+    LoadState, RecursiveDependencyLoadState, UntypedAssetId, UntypedAssetLoadFailedEvent,
+};
+use bevy::prelude::{
+    App, AssetServer, error, EventReader, Handle, in_state, IntoSystemConfigs, NextState, Plugin,
+    Res, ResMut, Resource, Scene, Startup, Update,
+};
+
+use crate::config::GameConfigHandle;
+use crate::state::GameState;
 
 #[derive(Resource, Debug, Default)]
 pub struct SceneAssets {
@@ -7,19 +16,91 @@ pub struct SceneAssets {
     pub spaceship: Handle<Scene>,
 }
 
+/// How far the [`GameState::Loading`] phase got, for the loading screen.
+#[derive(Resource, Debug, Default)]
+pub struct LoadingProgress {
+    pub loaded: usize,
+    pub total: usize,
+    /// Set when an asset failed to load; loading then stops and the game never starts.
+    pub error: Option<String>,
+}
+
 pub struct AssetLoaderPlugin;
 
 impl Plugin for AssetLoaderPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SceneAssets>()
-            .add_systems(Startup, load_assets);
+            .init_resource::<LoadingProgress>()
+            .add_systems(Startup, load_assets)
+            .add_systems(Update, track_loading.run_if(in_state(GameState::Loading)));
     }
 }
 
 fn load_assets(asset_server: Res<AssetServer>, mut scene_assets: ResMut<SceneAssets>) {
     *scene_assets = SceneAssets {
-        asteroid: asset_server.load("Planet.glb#Scene0"),
-        spaceship: asset_server.load("Spaceship.glb#Scene0"),
-        missile: asset_server.load("Missiles.glb#Scene0"),
+        asteroid: asset_server.load(ASTEROID_PATH),
+        spaceship: asset_server.load(SPACESHIP_PATH),
+        missile: asset_server.load(MISSILE_PATH),
+    }
+}
+
+fn track_loading(
+    mut next_state: ResMut<NextState<GameState>>,
+    mut progress: ResMut<LoadingProgress>,
+    asset_server: Res<AssetServer>,
+    scene_assets: Res<SceneAssets>,
+    config_handle: Option<Res<GameConfigHandle>>,
+    mut failed_events: EventReader<UntypedAssetLoadFailedEvent>,
+) {
+    if progress.error.is_some() {
+        return;
+    }
+
+    // A file that fails to parse only fails its base (un-labeled) asset, so the `#Scene0` handles
+    // below stay `Loading` forever; the failure event carries the base path instead.
+    if let Some(event) = failed_events.read().last() {
+        let message = format!("Failed to load {}: {}", event.path, event.error);
+        error!("{}", message);
+        progress.error = Some(message);
+        return;
+    }
+
+    let mut tracked: Vec<(&str, UntypedAssetId)> = vec![
+        (ASTEROID_PATH, scene_assets.asteroid.id().untyped()),
+        (SPACESHIP_PATH, scene_assets.spaceship.id().untyped()),
+        (MISSILE_PATH, scene_assets.missile.id().untyped()),
+    ];
+    if let Some(config_handle) = config_handle {
+        tracked.push(("game config", config_handle.0.id().untyped()));
+    }
+
+    let mut loaded = 0;
+    for (path, id) in tracked.iter().copied() {
+        if let LoadState::Failed(err) = asset_server.load_state(id) {
+            let message = format!("Failed to load {}: {}", path, err);
+            error!("{}", message);
+            progress.error = Some(message);
+            return;
+        }
+        if asset_server.recursive_dependency_load_state(id) == RecursiveDependencyLoadState::Failed
+        {
+            let message = format!("Failed to load a dependency of {}", path);
+            error!("{}", message);
+            progress.error = Some(message);
+            return;
+        }
+        if asset_server.is_loaded_with_dependencies(id) {
+            loaded += 1;
+        }
+    }
+
+    progress.loaded = loaded;
+    progress.total = tracked.len();
+    if loaded == tracked.len() {
+        next_state.set(GameState::MainMenu);
     }
 }
+
+const ASTEROID_PATH: &str = "Planet.glb#Scene0";
+const SPACESHIP_PATH: &str = "Spaceship.glb#Scene0";
+const MISSILE_PATH: &str = "Missiles.glb#Scene0";
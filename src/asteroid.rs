@@ -1,15 +1,21 @@
-use std::ops::Range;
+use std::time::Duration;
 
 use bevy::prelude::{
-    App, Commands, Component, default, IntoSystemConfigs, Plugin, Query, Res, ResMut, Resource,
-    SceneBundle, StateScoped, Time, Timer, TimerMode, Transform, Update, Vec3, With,
+    App, Bundle, Commands, Component, default, EventReader, FixedUpdate, IntoSystemConfigs, OnEnter,
+    Plugin, Query, Res, ResMut, Resource, resource_changed, SceneBundle, StateScoped, Time, Timer,
+    TimerMode, Transform, Vec3, With,
 };
 use rand::Rng;
 
 use crate::asset_loader::SceneAssets;
 use crate::collision_detection::{Collider, CollisionDamage};
+use crate::config::{AsteroidConfig, GameConfig};
+use crate::despawn::{despawn_dead_entities, EntityDied, EntityKind};
 use crate::health::Health;
+use crate::interpolation::TransformInterpolation;
+use crate::layers::ASTEROID_LAYERS;
 use crate::movement::{Acceleration, MovingObjectBundle, Velocity};
+use crate::rng::GameRng;
 use crate::schedule::InGameSet;
 use crate::state::GameState;
 
@@ -18,20 +24,65 @@ pub struct AsteroidPlugin;
 impl Plugin for AsteroidPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(SpawnTimer {
-            timer: Timer::from_seconds(SPAWN_TIME_SECONDS, TimerMode::Repeating),
+            timer: Timer::new(Duration::ZERO, TimerMode::Repeating),
         })
+        .insert_resource(WaveTimer {
+            timer: Timer::new(Duration::ZERO, TimerMode::Repeating),
+        })
+        .init_resource::<Wave>()
+        .add_systems(OnEnter(GameState::InGame), reset_wave)
+        .add_systems(
+            FixedUpdate,
+            (
+                apply_spawn_time_config.run_if(resource_changed::<GameConfig>),
+                advance_wave,
+                spawn_asteroid,
+                rotate_asteroids,
+            )
+                .chain()
+                .in_set(InGameSet::EntityUpdates),
+        )
         .add_systems(
-            Update,
-            (spawn_asteroid, rotate_asteroids).in_set(InGameSet::EntityUpdates),
+            FixedUpdate,
+            split_destroyed_asteroids
+                .after_ignore_deferred(despawn_dead_entities)
+                .in_set(InGameSet::DespawnEntities),
         );
     }
 }
 
+fn apply_spawn_time_config(
+    config: Res<GameConfig>,
+    mut spawn_timer: ResMut<SpawnTimer>,
+    mut wave_timer: ResMut<WaveTimer>,
+) {
+    spawn_timer
+        .timer
+        .set_duration(Duration::from_secs_f32(config.asteroid.spawn_time_seconds));
+    wave_timer
+        .timer
+        .set_duration(Duration::from_secs_f32(config.asteroid.wave_seconds));
+}
+
+fn reset_wave(mut wave: ResMut<Wave>, mut wave_timer: ResMut<WaveTimer>) {
+    *wave = Wave::default();
+    wave_timer.timer.reset();
+}
+
+fn advance_wave(mut wave: ResMut<Wave>, mut wave_timer: ResMut<WaveTimer>, time: Res<Time>) {
+    wave_timer.timer.tick(time.delta());
+    if wave_timer.timer.just_finished() {
+        wave.number += 1;
+    }
+}
+
 fn spawn_asteroid(
     mut commands: Commands,
     mut spawn_timer: ResMut<SpawnTimer>,
     time: Res<Time>,
     scene_assets: Res<SceneAssets>,
+    mut rng: ResMut<GameRng>,
+    config: Res<GameConfig>,
 ) {
     spawn_timer.timer.tick(time.delta());
     if !spawn_timer.timer.just_finished() {
@@ -39,58 +90,150 @@ fn spawn_asteroid(
     }
 
     // calculate asteroid data
-    let mut rng = rand::thread_rng();
+    let bounds_policy = config.arena.asteroid;
+    let config = &config.asteroid;
     let translation = Vec3::new(
-        rng.gen_range(SPAWN_RANGE_X),
+        rng.gen_range(config.spawn_range_x.clone()),
         0.0,
-        rng.gen_range(SPAWN_RANGE_Z),
+        rng.gen_range(config.spawn_range_z.clone()),
     );
-    let mut random_unit_vector =
-        || Vec3::new(rng.gen_range(-1.0..1.0), 0.0, rng.gen_range(-1.0..1.0)).normalize_or_zero();
-    let velocity = random_unit_vector() * VELOCITY_SCALAR;
-    let acceleration = random_unit_vector() * ACCELERATION_SCALAR;
+    let velocity = random_unit_vector(&mut rng) * config.velocity_scalar;
+    let acceleration = random_unit_vector(&mut rng) * config.acceleration_scalar;
 
     commands.spawn((
-        Asteroid,
+        asteroid_bundle(
+            &scene_assets,
+            config,
+            config.size,
+            translation,
+            velocity,
+            acceleration,
+        ),
+        bounds_policy,
+    ));
+}
+
+fn split_destroyed_asteroids(
+    mut commands: Commands,
+    mut event_reader: EventReader<EntityDied>,
+    query: Query<(&Velocity, &Acceleration)>,
+    scene_assets: Res<SceneAssets>,
+    mut rng: ResMut<GameRng>,
+    config: Res<GameConfig>,
+) {
+    let bounds_policy = config.arena.asteroid;
+    let config = &config.asteroid;
+    for event in event_reader.read() {
+        let EntityKind::Asteroid { size } = event.kind else {
+            continue;
+        };
+        if size <= config.min_size {
+            continue;
+        }
+        let Ok((velocity, acceleration)) = query.get(event.entity) else {
+            continue;
+        };
+
+        let fragment_count = rng.gen_range(config.min_fragments..=config.max_fragments);
+        for _ in 0..fragment_count {
+            let scatter = random_unit_vector(&mut rng) * config.fragment_scatter_speed;
+            commands.spawn((
+                asteroid_bundle(
+                    &scene_assets,
+                    config,
+                    size - 1,
+                    event.position,
+                    velocity.value + scatter,
+                    acceleration.value,
+                ),
+                bounds_policy,
+            ));
+        }
+    }
+}
+
+fn asteroid_bundle(
+    scene_assets: &SceneAssets,
+    config: &AsteroidConfig,
+    size: u32,
+    translation: Vec3,
+    velocity: Vec3,
+    acceleration: Vec3,
+) -> impl Bundle {
+    // Asteroids of the spawned size are unscaled, each smaller tier shrinks by the fragment scale.
+    // Saturating, since a hot reload can lower `config.size` below live fragments.
+    let scale = config
+        .fragment_scale
+        .powi(config.size.saturating_sub(size) as i32);
+    (
+        Asteroid::new(size),
+        ASTEROID_LAYERS,
         StateScoped(GameState::InGame),
-        Health::new(HEALTH),
-        CollisionDamage::new(COLLISION_DAMAGE),
+        Health::new(config.health * scale),
+        CollisionDamage::new(config.collision_damage),
         MovingObjectBundle {
             velocity: Velocity::new(velocity),
             acceleration: Acceleration::new(acceleration),
-            collider: Collider::new(1.0),
+            collider: Collider::new(COLLIDER_RADIUS * scale),
             model: SceneBundle {
                 scene: scene_assets.asteroid.clone(),
-                transform: Transform::from_translation(translation),
+                transform: Transform::from_translation(translation).with_scale(Vec3::splat(scale)),
                 ..default()
             },
+            interpolation: TransformInterpolation::default(),
         },
-    ));
+    )
+}
+
+fn random_unit_vector(rng: &mut GameRng) -> Vec3 {
+    Vec3::new(rng.gen_range(-1.0..1.0), 0.0, rng.gen_range(-1.0..1.0)).normalize_or_zero()
 }
 
-fn rotate_asteroids(mut query: Query<&mut Transform, With<Asteroid>>, time: Res<Time>) {
+fn rotate_asteroids(
+    mut query: Query<&mut Transform, With<Asteroid>>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let rotation_speed = config.asteroid.rotation_speed;
     for mut transform in query.iter_mut() {
-        transform.rotate_local_x(ROTATION_SPEED * time.delta_seconds());
-        transform.rotate_local_y(ROTATION_SPEED * time.delta_seconds());
-        transform.rotate_local_z(ROTATION_SPEED * time.delta_seconds());
+        transform.rotate_local_x(rotation_speed * time.delta_seconds());
+        transform.rotate_local_y(rotation_speed * time.delta_seconds());
+        transform.rotate_local_z(rotation_speed * time.delta_seconds());
     }
 }
 
 #[derive(Component, Debug)]
-pub struct Asteroid;
+pub struct Asteroid {
+    /// Size tier; destroyed asteroids split into fragments one tier smaller.
+    pub size: u32,
+}
+
+impl Asteroid {
+    pub fn new(size: u32) -> Self {
+        Self { size }
+    }
+}
 
 #[derive(Resource, Debug)]
 pub struct SpawnTimer {
     timer: Timer,
 }
 
-const VELOCITY_SCALAR: f32 = 5.0;
-const ACCELERATION_SCALAR: f32 = 1.0;
+/// Current wave of the run, starting at 1 and advancing every `asteroid.wave_seconds`.
+#[derive(Resource, Debug)]
+pub struct Wave {
+    pub number: u32,
+}
 
-const SPAWN_RANGE_X: Range<f32> = -25.0..25.0;
-const SPAWN_RANGE_Z: Range<f32> = 0.0..25.0;
-const SPAWN_TIME_SECONDS: f32 = 1.0;
+impl Default for Wave {
+    fn default() -> Self {
+        Self { number: 1 }
+    }
+}
+
+#[derive(Resource, Debug)]
+pub struct WaveTimer {
+    timer: Timer,
+}
 
-const ROTATION_SPEED: f32 = 2.0;
-const HEALTH: f32 = 50.0;
-const COLLISION_DAMAGE: f32 = 35.0;
+const COLLIDER_RADIUS: f32 = 1.0;
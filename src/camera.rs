@@ -1,19 +1,266 @@
 use bevy::app::App;
-use bevy::prelude::{Camera3dBundle, Commands, default, Plugin, Startup, Transform, Vec3};
+use bevy::prelude::{
+    ButtonInput, Camera, Camera3dBundle, Commands, Component, default, FixedUpdate, in_state,
+    IntoSystemConfigs, KeyCode, Plugin, Query, Real, Res, ResMut, Resource, resource_changed,
+    Startup, Time, Transform, Update, Vec3, With, Without,
+};
+use serde::Deserialize;
 
-const CAMERA_DISTANCE: f32 = 80.0;
+use crate::action::{Action, ActionState};
+use crate::arena::ArenaBounds;
+use crate::asteroid::Asteroid;
+use crate::config::GameConfig;
+use crate::spaceship::Spaceship;
+use crate::state::GameState;
+
+pub const CAMERA_DISTANCE: f32 = 80.0;
+/// Offsets the noise inputs of the shake axes so they move independently.
+const NOISE_CHANNEL_SPACING: f32 = 100.0;
 
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_camera);
+        app.init_resource::<CameraShake>()
+            .add_systems(Startup, spawn_camera)
+            .add_systems(
+                FixedUpdate,
+                cycle_camera_mode.run_if(in_state(GameState::InGame)),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_camera_config.run_if(resource_changed::<GameConfig>),
+                    update_camera_rig,
+                    fly_free_camera,
+                    apply_camera_rig,
+                )
+                    .chain(),
+            );
     }
 }
 
 fn spawn_camera(mut commands: Commands) {
-    commands.spawn(Camera3dBundle {
-        transform: Transform::from_xyz(0.0, CAMERA_DISTANCE, 0.0).looking_at(Vec3::ZERO, Vec3::Z),
-        ..default()
+    let transform = Transform::from_xyz(0.0, CAMERA_DISTANCE, 0.0).looking_at(Vec3::ZERO, Vec3::Z);
+    commands.spawn((
+        Camera3dBundle {
+            transform,
+            ..default()
+        },
+        CameraController::new(transform),
+    ));
+}
+
+fn apply_camera_config(config: Res<GameConfig>, mut query: Query<&mut CameraController>) {
+    for mut controller in query.iter_mut() {
+        controller.mode = config.camera.mode;
+    }
+}
+
+fn cycle_camera_mode(action_state: Res<ActionState>, mut query: Query<&mut CameraController>) {
+    if !action_state.just_pressed(Action::CycleCamera) {
+        return;
+    }
+    for mut controller in query.iter_mut() {
+        controller.mode = controller.mode.next();
+    }
+}
+
+/// Moves the rig towards where the current mode wants the camera, smoothed over time.
+fn update_camera_rig(
+    mut camera_query: Query<&mut CameraController>,
+    spaceship_query: Query<&Transform, With<Spaceship>>,
+    asteroid_query: Query<&Transform, (With<Asteroid>, Without<Spaceship>)>,
+    bounds: Res<ArenaBounds>,
+    config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    let config = &config.camera;
+    let spaceship = spaceship_query.get_single().ok();
+    let threats = spaceship.map_or(0, |spaceship| {
+        asteroid_query
+            .iter()
+            .filter(|asteroid| {
+                asteroid.translation.distance(spaceship.translation) < config.threat_radius
+            })
+            .count()
     });
+    let zoom = 1.0 + (threats as f32 * config.zoom_per_threat).min(config.max_zoom);
+    let blend = 1.0 - (-config.smoothing * time.delta_seconds()).exp();
+
+    for mut controller in camera_query.iter_mut() {
+        let target = match (controller.mode, spaceship) {
+            (CameraMode::TopDown, _) | (CameraMode::Follow | CameraMode::Chase, None) => {
+                top_down(Vec3::ZERO, CAMERA_DISTANCE * zoom)
+            }
+            (CameraMode::Follow, Some(spaceship)) => top_down(
+                clamp_focus(spaceship.translation, &bounds),
+                CAMERA_DISTANCE * zoom,
+            ),
+            (CameraMode::Chase, Some(spaceship)) => {
+                // The spaceship model faces its local +Z.
+                let heading = -spaceship.forward().as_vec3();
+                let eye = spaceship.translation - heading * config.chase_distance * zoom
+                    + Vec3::Y * config.chase_height * zoom;
+                Transform::from_translation(eye).looking_at(
+                    spaceship.translation + heading * config.chase_look_ahead,
+                    Vec3::Y,
+                )
+            }
+            (CameraMode::FreeFly, _) => continue,
+        };
+
+        let rig = &mut controller.transform;
+        rig.translation = rig.translation.lerp(target.translation, blend);
+        rig.rotation = rig.rotation.slerp(target.rotation, blend);
+    }
+}
+
+fn top_down(focus: Vec3, height: f32) -> Transform {
+    Transform::from_translation(focus + Vec3::Y * height).looking_at(focus, Vec3::Z)
+}
+
+/// Keeps the focus inside the arena, so the camera never drifts past where the spaceship can go.
+fn clamp_focus(focus: Vec3, bounds: &ArenaBounds) -> Vec3 {
+    let limit = bounds.half_extents;
+    Vec3::new(
+        focus.x.clamp(-limit.x, limit.x),
+        focus.y,
+        focus.z.clamp(-limit.y, limit.y),
+    )
+}
+
+/// Debug camera steered with the arrow keys, so it does not fight the spaceship controls.
+fn fly_free_camera(
+    mut query: Query<&mut CameraController>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    let axis = |positive: KeyCode, negative: KeyCode| {
+        keyboard_input.pressed(positive) as i8 as f32
+            - keyboard_input.pressed(negative) as i8 as f32
+    };
+    let movement = Vec3::new(
+        axis(KeyCode::ArrowRight, KeyCode::ArrowLeft),
+        axis(KeyCode::PageUp, KeyCode::PageDown),
+        -axis(KeyCode::ArrowUp, KeyCode::ArrowDown),
+    );
+
+    for mut controller in query.iter_mut() {
+        if controller.mode != CameraMode::FreeFly {
+            continue;
+        }
+        let rig = &mut controller.transform;
+        let rotation = rig.rotation;
+        rig.translation +=
+            rotation * movement * config.camera.free_fly_speed * time.delta_seconds();
+    }
+}
+
+fn apply_camera_rig(
+    mut shake: ResMut<CameraShake>,
+    mut query: Query<(&mut Transform, &CameraController), With<Camera>>,
+    config: Res<GameConfig>,
+    time: Res<Time<Real>>,
+) {
+    let config = &config.camera;
+    // Real time, so the shake keeps moving through a hit-stop.
+    shake.elapsed += time.delta_seconds();
+    shake.trauma = (shake.trauma - config.trauma_decay * time.delta_seconds()).max(0.0);
+
+    // Squaring makes light hits subtle while heavy ones still shake hard.
+    let strength = shake.trauma * shake.trauma;
+    let sample = |channel: f32| {
+        strength
+            * value_noise(shake.elapsed * config.shake_frequency + channel * NOISE_CHANNEL_SPACING)
+    };
+    let offset = Vec3::new(sample(0.0), 0.0, sample(1.0)) * config.shake_max_offset;
+    let roll = sample(2.0) * config.shake_max_roll_degrees.to_radians();
+
+    for (mut transform, controller) in query.iter_mut() {
+        *transform = controller.transform;
+        transform.translation += offset;
+        transform.rotate_local_z(roll);
+    }
+}
+
+/// Smooth noise in `-1.0..=1.0`, interpolated between random values at whole numbers.
+fn value_noise(x: f32) -> f32 {
+    let cell = x.floor();
+    let t = x - cell;
+    let smooth_t = t * t * (3.0 - 2.0 * t);
+    let cell = cell as i32;
+    lattice_value(cell) + (lattice_value(cell.wrapping_add(1)) - lattice_value(cell)) * smooth_t
+}
+
+/// Deterministic pseudo-random value in `-1.0..=1.0` for a lattice point.
+fn lattice_value(cell: i32) -> f32 {
+    let mut hash = cell as u32;
+    hash = (hash ^ 61) ^ (hash >> 16);
+    hash = hash.wrapping_mul(9);
+    hash ^= hash >> 4;
+    hash = hash.wrapping_mul(0x27d4_eb2d);
+    hash ^= hash >> 15;
+    hash as f32 / u32::MAX as f32 * 2.0 - 1.0
+}
+
+/// How the camera picks its viewpoint.
+#[derive(Deserialize, Clone, Copy, Eq, PartialEq, Default, Debug)]
+pub enum CameraMode {
+    /// Fixed above the arena center.
+    #[default]
+    TopDown,
+    /// Above the spaceship, with its focus kept inside the arena bounds.
+    Follow,
+    /// Behind and above the spaceship, looking where it is heading.
+    Chase,
+    /// Moved freely with the arrow keys and Page Up/Down, for debugging.
+    FreeFly,
+}
+
+impl CameraMode {
+    fn next(self) -> Self {
+        match self {
+            CameraMode::TopDown => CameraMode::Follow,
+            CameraMode::Follow => CameraMode::Chase,
+            CameraMode::Chase => CameraMode::FreeFly,
+            CameraMode::FreeFly => CameraMode::TopDown,
+        }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct CameraController {
+    pub mode: CameraMode,
+    /// Smoothed camera transform, before any shake is added.
+    transform: Transform,
+}
+
+impl CameraController {
+    pub fn new(transform: Transform) -> Self {
+        Self {
+            mode: CameraMode::default(),
+            transform,
+        }
+    }
+}
+
+/// Trauma-based camera shake. Any system can add trauma; it decays on its own.
+#[derive(Resource, Debug, Default)]
+pub struct CameraShake {
+    /// Shake amount in `0.0..=1.0`.
+    trauma: f32,
+    /// Seconds fed to the noise, so offsets drift smoothly instead of jumping every frame.
+    elapsed: f32,
+}
+
+impl CameraShake {
+    pub fn trauma(&self) -> f32 {
+        self.trauma
+    }
+
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
 }
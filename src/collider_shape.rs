@@ -0,0 +1,290 @@
+use bevy::prelude::{Quat, Vec3};
+use serde::Deserialize;
+
+/// Collision volume in the entity's local space.
+///
+/// Dimensions are in world units: the entity's rotation and translation apply, its scale does not.
+#[derive(Deserialize, Clone, PartialEq, Debug)]
+pub enum ColliderShape {
+    Sphere {
+        radius: f32,
+    },
+    /// Sphere swept along the segment from `start` to `end`.
+    Capsule {
+        start: Vec3,
+        end: Vec3,
+        radius: f32,
+    },
+    /// Box that turns with the entity.
+    Cuboid {
+        center: Vec3,
+        half_extents: Vec3,
+    },
+    /// Union of spheres, each given as its local center and radius.
+    Compound {
+        spheres: Vec<(Vec3, f32)>,
+    },
+}
+
+impl ColliderShape {
+    /// Radius of a sphere around the entity's origin that contains the whole shape.
+    pub fn bounding_radius(&self) -> f32 {
+        match self {
+            ColliderShape::Sphere { radius } => *radius,
+            ColliderShape::Capsule { start, end, radius } => {
+                start.length().max(end.length()) + radius
+            }
+            ColliderShape::Cuboid {
+                center,
+                half_extents,
+            } => center.length() + half_extents.length(),
+            ColliderShape::Compound { spheres } => spheres
+                .iter()
+                .map(|(center, radius)| center.length() + radius)
+                .fold(0.0, f32::max),
+        }
+    }
+
+    pub fn to_world(&self, translation: Vec3, rotation: Quat) -> WorldShape {
+        let point = |local: Vec3| translation + rotation * local;
+        match self {
+            ColliderShape::Sphere { radius } => WorldShape::Sphere {
+                center: translation,
+                radius: *radius,
+            },
+            ColliderShape::Capsule { start, end, radius } => WorldShape::Capsule {
+                start: point(*start),
+                end: point(*end),
+                radius: *radius,
+            },
+            ColliderShape::Cuboid {
+                center,
+                half_extents,
+            } => WorldShape::Cuboid {
+                center: point(*center),
+                axes: [rotation * Vec3::X, rotation * Vec3::Y, rotation * Vec3::Z],
+                half_extents: *half_extents,
+            },
+            ColliderShape::Compound { spheres } => WorldShape::Compound {
+                spheres: spheres
+                    .iter()
+                    .map(|&(center, radius)| (point(center), radius))
+                    .collect(),
+            },
+        }
+    }
+}
+
+/// A [`ColliderShape`] placed in the world, ready for the narrowphase.
+#[derive(Clone, PartialEq, Debug)]
+pub enum WorldShape {
+    Sphere {
+        center: Vec3,
+        radius: f32,
+    },
+    Capsule {
+        start: Vec3,
+        end: Vec3,
+        radius: f32,
+    },
+    Cuboid {
+        center: Vec3,
+        axes: [Vec3; 3],
+        half_extents: Vec3,
+    },
+    Compound {
+        spheres: Vec<(Vec3, f32)>,
+    },
+}
+
+impl WorldShape {
+    pub fn intersects(&self, other: &WorldShape) -> bool {
+        use WorldShape::*;
+
+        match (self, other) {
+            (Compound { spheres }, _) => spheres
+                .iter()
+                .any(|&(center, radius)| sphere_intersects(center, radius, other)),
+            (_, Compound { .. }) => other.intersects(self),
+            (&Sphere { center, radius }, _) => sphere_intersects(center, radius, other),
+            (_, &Sphere { center, radius }) => sphere_intersects(center, radius, self),
+            (
+                &Capsule { start, end, radius },
+                &Capsule {
+                    start: other_start,
+                    end: other_end,
+                    radius: other_radius,
+                },
+            ) => segment_distance(start, end, other_start, other_end) < radius + other_radius,
+            (
+                &Capsule { start, end, radius },
+                &Cuboid {
+                    center,
+                    axes,
+                    half_extents,
+                },
+            )
+            | (
+                &Cuboid {
+                    center,
+                    axes,
+                    half_extents,
+                },
+                &Capsule { start, end, radius },
+            ) => segment_cuboid_distance(start, end, center, axes, half_extents) < radius,
+            (
+                &Cuboid {
+                    center,
+                    axes,
+                    half_extents,
+                },
+                &Cuboid {
+                    center: other_center,
+                    axes: other_axes,
+                    half_extents: other_half_extents,
+                },
+            ) => cuboids_overlap(
+                (center, axes, half_extents),
+                (other_center, other_axes, other_half_extents),
+            ),
+        }
+    }
+}
+
+fn sphere_intersects(center: Vec3, radius: f32, other: &WorldShape) -> bool {
+    match *other {
+        WorldShape::Sphere {
+            center: other_center,
+            radius: other_radius,
+        } => center.distance(other_center) < radius + other_radius,
+        WorldShape::Capsule {
+            start,
+            end,
+            radius: other_radius,
+        } => center.distance(closest_point_on_segment(center, start, end)) < radius + other_radius,
+        WorldShape::Cuboid {
+            center: other_center,
+            axes,
+            half_extents,
+        } => point_cuboid_distance(center, other_center, axes, half_extents) < radius,
+        WorldShape::Compound { ref spheres } => {
+            spheres.iter().any(|&(other_center, other_radius)| {
+                center.distance(other_center) < radius + other_radius
+            })
+        }
+    }
+}
+
+fn closest_point_on_segment(point: Vec3, start: Vec3, end: Vec3) -> Vec3 {
+    let direction = end - start;
+    let length_squared = direction.length_squared();
+    if length_squared == 0.0 {
+        return start;
+    }
+    let t = ((point - start).dot(direction) / length_squared).clamp(0.0, 1.0);
+    start + direction * t
+}
+
+/// Smallest distance between the segments `start_a..end_a` and `start_b..end_b`.
+fn segment_distance(start_a: Vec3, end_a: Vec3, start_b: Vec3, end_b: Vec3) -> f32 {
+    let direction_a = end_a - start_a;
+    let direction_b = end_b - start_b;
+    let offset = start_a - start_b;
+    let length_a = direction_a.length_squared();
+    let length_b = direction_b.length_squared();
+    let f = direction_b.dot(offset);
+
+    let (s, t) = if length_a <= f32::EPSILON && length_b <= f32::EPSILON {
+        (0.0, 0.0)
+    } else if length_a <= f32::EPSILON {
+        (0.0, (f / length_b).clamp(0.0, 1.0))
+    } else {
+        let c = direction_a.dot(offset);
+        if length_b <= f32::EPSILON {
+            ((-c / length_a).clamp(0.0, 1.0), 0.0)
+        } else {
+            let b = direction_a.dot(direction_b);
+            let denominator = length_a * length_b - b * b;
+            let s = if denominator != 0.0 {
+                ((b * f - c * length_b) / denominator).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let t = (b * s + f) / length_b;
+            if t < 0.0 {
+                ((-c / length_a).clamp(0.0, 1.0), 0.0)
+            } else if 1.0 < t {
+                (((b - c) / length_a).clamp(0.0, 1.0), 1.0)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    (start_a + direction_a * s).distance(start_b + direction_b * t)
+}
+
+fn point_cuboid_distance(point: Vec3, center: Vec3, axes: [Vec3; 3], half_extents: Vec3) -> f32 {
+    let offset = point - center;
+    let mut closest = center;
+    for (axis, half_extent) in axes.into_iter().zip(half_extents.to_array()) {
+        closest += axis * offset.dot(axis).clamp(-half_extent, half_extent);
+    }
+    point.distance(closest)
+}
+
+fn segment_cuboid_distance(
+    start: Vec3,
+    end: Vec3,
+    center: Vec3,
+    axes: [Vec3; 3],
+    half_extents: Vec3,
+) -> f32 {
+    // The distance to a convex shape is convex along the segment, so a ternary search finds its
+    // minimum.
+    let distance = |t: f32| point_cuboid_distance(start.lerp(end, t), center, axes, half_extents);
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..SEGMENT_SEARCH_STEPS {
+        let third = (high - low) / 3.0;
+        if distance(low + third) < distance(high - third) {
+            high -= third;
+        } else {
+            low += third;
+        }
+    }
+    distance((low + high) / 2.0)
+}
+
+/// Separating axis test between two oriented boxes, given as `(center, axes, half_extents)`.
+fn cuboids_overlap(a: (Vec3, [Vec3; 3], Vec3), b: (Vec3, [Vec3; 3], Vec3)) -> bool {
+    let (center_a, axes_a, half_extents_a) = a;
+    let (center_b, axes_b, half_extents_b) = b;
+    let offset = center_b - center_a;
+    let projected_radius = |axes: [Vec3; 3], half_extents: Vec3, axis: Vec3| {
+        axes.into_iter()
+            .zip(half_extents.to_array())
+            .map(|(box_axis, half_extent)| half_extent * box_axis.dot(axis).abs())
+            .sum::<f32>()
+    };
+
+    let mut candidate_axes = vec![];
+    candidate_axes.extend(axes_a);
+    candidate_axes.extend(axes_b);
+    for axis_a in axes_a {
+        for axis_b in axes_b {
+            candidate_axes.push(axis_a.cross(axis_b));
+        }
+    }
+
+    candidate_axes
+        .into_iter()
+        // Parallel edges give no cross product; their face axes are already covered.
+        .filter(|axis| f32::EPSILON < axis.length_squared())
+        .all(|axis| {
+            offset.dot(axis).abs()
+                < projected_radius(axes_a, half_extents_a, axis)
+                    + projected_radius(axes_b, half_extents_b, axis)
+        })
+}
+
+const SEGMENT_SEARCH_STEPS: usize = 32;
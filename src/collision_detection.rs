@@ -1,61 +1,89 @@
 use bevy::prelude::{
-    App, Component, Entity, Event, EventReader, EventWriter, GlobalTransform, IntoSystemConfigs,
-    Plugin, Query, Update, With,
+    App, BVec3, Children, Commands, Component, Entity, Event, EventReader, EventWriter, FixedUpdate,
+    GlobalTransform, HierarchyQueryExt, IntoSystemConfigs, IVec2, Plugin, Query, ResMut, Resource,
+    Update, Vec3, With, Without,
 };
-use bevy::utils::HashMap;
+use bevy::render::primitives::Aabb;
+use bevy::utils::{HashMap, HashSet};
 
-use crate::asteroid::Asteroid;
-use crate::health::Health;
+use crate::collider_shape::{ColliderShape, WorldShape};
+use crate::health::{apply_damage, DamageEvent, DamageKind, Invulnerable};
 use crate::schedule::InGameSet;
-use crate::spaceship::{Spaceship, SpaceshipMissile};
 
 pub struct CollisionDetectionPlugin;
 
 impl Plugin for CollisionDetectionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            collision_detection.in_set(InGameSet::CollisionDetection),
-        )
-        .add_systems(
-            Update,
-            (
-                (
-                    handle_collisions::<Asteroid>,
-                    handle_collisions::<Spaceship>,
-                    handle_collisions::<SpaceshipMissile>,
-                ),
-                apply_collision_damage,
+        app.insert_resource(CollisionGrid::new(GRID_CELL_SIZE))
+            .init_resource::<Contacts>()
+            .add_systems(Update, fit_colliders_to_meshes)
+            .add_systems(
+                FixedUpdate,
+                collision_detection.in_set(InGameSet::CollisionDetection),
             )
-                .chain()
-                .in_set(InGameSet::EntityUpdates),
-        )
-        .add_event::<CollisionEvent>();
+            .add_systems(
+                FixedUpdate,
+                (handle_collisions, send_collision_damage)
+                    .chain()
+                    .before(apply_damage)
+                    .in_set(InGameSet::EntityUpdates),
+            )
+            .add_event::<CollisionEvent>();
     }
 }
 
-fn collision_detection(mut query: Query<(Entity, &GlobalTransform, &mut Collider)>) {
-    let mut computed_colliding_entities_map: HashMap<Entity, Vec<Entity>> = HashMap::new();
-
-    // First phase: detect collisions.
-    for (entity_a, transform_a, collider_a) in query.iter() {
-        for (entity_b, transform_b, collider_b) in query.iter() {
-            if entity_a != entity_b {
-                let distance = transform_a
-                    .translation()
-                    .distance(transform_b.translation());
-                let is_collided: bool = distance < collider_a.radius + collider_b.radius;
-                if is_collided {
-                    computed_colliding_entities_map
-                        .entry(entity_a)
-                        .or_insert_with(Vec::new)
-                        .push(entity_b);
-                }
-            }
+fn collision_detection(
+    mut grid: ResMut<CollisionGrid>,
+    mut query: Query<(Entity, &GlobalTransform, &mut Collider)>,
+    mut fast_mover_query: Query<&mut FastMover>,
+    layers_query: Query<&CollisionLayers>,
+) {
+    // First phase: rebuild the broadphase grid from bounding spheres, sweeping fast movers from
+    // where they were last tick.
+    grid.clear();
+    let mut world_shapes: HashMap<Entity, (WorldShape, bool)> = HashMap::new();
+    for (entity, transform, collider) in query.iter() {
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let mut start = translation;
+        if let Ok(mut fast_mover) = fast_mover_query.get_mut(entity) {
+            start = fast_mover.previous_translation.unwrap_or(translation);
+            fast_mover.previous_translation = Some(translation);
         }
+        grid.insert_swept(entity, start, translation, collider.shape.bounding_radius());
+
+        let world_shape = match collider.shape {
+            // A sphere swept along its path is a capsule.
+            ColliderShape::Sphere { radius } if start != translation => WorldShape::Capsule {
+                start,
+                end: translation,
+                radius,
+            },
+            ref shape => shape.to_world(translation, rotation),
+        };
+        let is_sphere = matches!(collider.shape, ColliderShape::Sphere { .. });
+        world_shapes.insert(entity, (world_shape, is_sphere));
     }
 
-    // Second phase: update colliders.
+    // Second phase: detect collisions. The grid already settles sphere pairs exactly, any other
+    // shape still goes through the narrowphase.
+    let mut computed_colliding_entities_map: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    let layers = |entity| layers_query.get(entity).copied().unwrap_or_default();
+    grid.for_each_collision(|entity_a, entity_b| {
+        if !layers(entity_a).interacts_with(&layers(entity_b)) {
+            return;
+        }
+        let (shape_a, is_sphere_a) = &world_shapes[&entity_a];
+        let (shape_b, is_sphere_b) = &world_shapes[&entity_b];
+        if !(is_sphere_a & is_sphere_b) && !shape_a.intersects(shape_b) {
+            return;
+        }
+        computed_colliding_entities_map
+            .entry(entity_a)
+            .or_insert_with(Vec::new)
+            .push(entity_b);
+    });
+
+    // Third phase: update colliders.
     for (entity, _, mut collider) in query.iter_mut() {
         collider.colliding_entities.clear();
         if let Some(computed_colliding_entities) = computed_colliding_entities_map.get(&entity) {
@@ -66,82 +94,351 @@ fn collision_detection(mut query: Query<(Entity, &GlobalTransform, &mut Collider
     }
 }
 
-fn handle_collisions<T: Component>(
+fn fit_colliders_to_meshes(
+    mut commands: Commands,
+    mut query: Query<(Entity, &GlobalTransform, &mut Collider), With<FitColliderToMesh>>,
+    children_query: Query<&Children>,
+    mesh_query: Query<(&Aabb, &GlobalTransform)>,
+) {
+    for (entity, transform, mut collider) in query.iter_mut() {
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let mut min = Vec3::MAX;
+        let mut max = Vec3::MIN;
+        for descendant in children_query.iter_descendants(entity) {
+            let Ok((aabb, mesh_transform)) = mesh_query.get(descendant) else {
+                continue;
+            };
+            let (aabb_min, aabb_max) = (Vec3::from(aabb.min()), Vec3::from(aabb.max()));
+            for corner in 0..8 {
+                let local_corner = Vec3::select(
+                    BVec3::new(corner & 1 != 0, corner & 2 != 0, corner & 4 != 0),
+                    aabb_max,
+                    aabb_min,
+                );
+                let world_corner = mesh_transform.transform_point(local_corner);
+                let corner = rotation.inverse() * (world_corner - translation);
+                min = min.min(corner);
+                max = max.max(corner);
+            }
+        }
+        // The scene has not spawned its meshes yet.
+        if max.cmplt(min).any() {
+            continue;
+        }
+
+        collider.shape = ColliderShape::Cuboid {
+            center: (min + max) / 2.0,
+            half_extents: (max - min) / 2.0,
+        };
+        commands.entity(entity).remove::<FitColliderToMesh>();
+    }
+}
+
+fn handle_collisions(
     mut event_writer: EventWriter<CollisionEvent>,
-    query: Query<(Entity, &Collider), With<T>>,
+    mut contacts: ResMut<Contacts>,
+    query: Query<(Entity, &Collider)>,
 ) {
+    // Both colliders list each other, so keep every pair once, ordered by entity.
+    let mut pairs = vec![];
+    let mut current = HashSet::new();
     for (entity, collider) in query.iter() {
         for &collided_entity in collider.colliding_entities.iter() {
-            // Entity collided with another entity of the same type.
-            if query.get(collided_entity).is_ok() {
-                continue;
+            let pair = (entity.min(collided_entity), entity.max(collided_entity));
+            if current.insert(pair) {
+                pairs.push(pair);
             }
+        }
+    }
 
-            event_writer.send(CollisionEvent::new(entity, collided_entity));
-            break;
+    let previous: HashSet<(Entity, Entity)> = contacts.pairs.iter().copied().collect();
+    for &(entity_a, entity_b) in pairs.iter() {
+        let phase = if previous.contains(&(entity_a, entity_b)) {
+            CollisionPhase::Stay
+        } else {
+            CollisionPhase::Started
+        };
+        event_writer.send(CollisionEvent::new(entity_a, entity_b, phase));
+    }
+    for &(entity_a, entity_b) in contacts.pairs.iter() {
+        if !current.contains(&(entity_a, entity_b)) {
+            event_writer.send(CollisionEvent::new(
+                entity_a,
+                entity_b,
+                CollisionPhase::Ended,
+            ));
         }
     }
+    contacts.pairs = pairs;
 }
 
-fn apply_collision_damage(
+/// Deals [`CollisionDamage`] on every tick a pair touches, not only when they first meet.
+/// [`Invulnerable`] entities deal none, so a respawned spaceship can't ram for free.
+fn send_collision_damage(
     mut event_reader: EventReader<CollisionEvent>,
-    mut health_query: Query<&mut Health>,
-    collision_damage_query: Query<&CollisionDamage>,
+    mut event_writer: EventWriter<DamageEvent>,
+    collision_damage_query: Query<&CollisionDamage, Without<Invulnerable>>,
 ) {
-    for &CollisionEvent {
-        entity,
-        collided_entity,
-    } in event_reader.read()
-    {
-        let Ok(mut health) = health_query.get_mut(entity) else {
+    for event in event_reader.read() {
+        if event.phase == CollisionPhase::Ended {
             continue;
-        };
+        }
+        let pair = (event.entity_a, event.entity_b);
+        for (entity, collided_entity) in [pair, (pair.1, pair.0)] {
+            let Ok(collision_damage) = collision_damage_query.get(collided_entity) else {
+                continue;
+            };
+            event_writer.send(DamageEvent::new(
+                entity,
+                collided_entity,
+                collision_damage.value,
+                collision_damage.kind,
+            ));
+        }
+    }
+}
 
-        let Ok(collision_damage) = collision_damage_query.get(collided_entity) else {
-            continue;
-        };
+/// Uniform grid over the XZ plane used as the collision broadphase.
+///
+/// Every collider is inserted into each cell its bounding square overlaps, so only colliders
+/// sharing a cell are passed on to the exact sphere test. Swept colliders cover the cells along
+/// their whole path and are tested at their closest approach.
+#[derive(Resource, Debug)]
+pub struct CollisionGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<usize>>,
+    entries: Vec<GridEntry>,
+}
+
+#[derive(Debug)]
+struct GridEntry {
+    entity: Entity,
+    start: Vec3,
+    end: Vec3,
+    radius: f32,
+}
+
+impl CollisionGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            entries: vec![],
+        }
+    }
 
-        health.value -= collision_damage.value;
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.entries.clear();
+    }
+
+    pub fn insert(&mut self, entity: Entity, translation: Vec3, radius: f32) {
+        self.insert_swept(entity, translation, translation, radius);
+    }
+
+    /// Inserts a sphere that moved from `start` to `end` during the last step.
+    pub fn insert_swept(&mut self, entity: Entity, start: Vec3, end: Vec3, radius: f32) {
+        let index = self.entries.len();
+        let (min, max) = self.cell_range(start, end, radius);
+        for x in min.x..=max.x {
+            for z in min.y..=max.y {
+                self.cells.entry(IVec2::new(x, z)).or_default().push(index);
+            }
+        }
+        self.entries.push(GridEntry {
+            entity,
+            start,
+            end,
+            radius,
+        });
+    }
+
+    /// Calls `f(entity, collided_entity)` for every ordered pair of overlapping colliders.
+    ///
+    /// Pairs are visited in insertion order, matching a brute-force nested loop over the inserted
+    /// colliders.
+    pub fn for_each_collision(&self, mut f: impl FnMut(Entity, Entity)) {
+        let mut candidates: Vec<usize> = vec![];
+        for (index_a, entry_a) in self.entries.iter().enumerate() {
+            self.collect_candidates(index_a, &mut candidates);
+            for &index_b in candidates.iter() {
+                let entry_b = &self.entries[index_b];
+                let distance = closest_approach(entry_a, entry_b);
+                let is_collided: bool = distance < entry_a.radius + entry_b.radius;
+                if is_collided {
+                    f(entry_a.entity, entry_b.entity);
+                }
+            }
+        }
+    }
+
+    /// Number of ordered pairs that share a cell and get the exact test. A brute-force loop tests
+    /// all `n * (n - 1)`.
+    pub fn candidate_pair_count(&self) -> usize {
+        let mut candidates: Vec<usize> = vec![];
+        (0..self.entries.len())
+            .map(|index| {
+                self.collect_candidates(index, &mut candidates);
+                candidates.len()
+            })
+            .sum()
+    }
+
+    /// Fills `candidates` with the sorted, deduplicated entries sharing a cell with `index`.
+    fn collect_candidates(&self, index: usize, candidates: &mut Vec<usize>) {
+        let entry = &self.entries[index];
+        candidates.clear();
+        let (min, max) = self.cell_range(entry.start, entry.end, entry.radius);
+        for x in min.x..=max.x {
+            for z in min.y..=max.y {
+                if let Some(cell) = self.cells.get(&IVec2::new(x, z)) {
+                    candidates.extend(cell.iter().copied().filter(|&other| other != index));
+                }
+            }
+        }
+        candidates.sort_unstable();
+        candidates.dedup();
+    }
+
+    fn cell_range(&self, start: Vec3, end: Vec3, radius: f32) -> (IVec2, IVec2) {
+        let cell = |value: f32| (value / self.cell_size).floor() as i32;
+        let min = start.min(end) - radius;
+        let max = start.max(end) + radius;
+        (
+            IVec2::new(cell(min.x), cell(min.z)),
+            IVec2::new(cell(max.x), cell(max.z)),
+        )
     }
 }
 
+/// Smallest distance between two entries while both move linearly from `start` to `end`.
+fn closest_approach(a: &GridEntry, b: &GridEntry) -> f32 {
+    let offset = a.start - b.start;
+    let relative_motion = (a.end - a.start) - (b.end - b.start);
+    let length_squared = relative_motion.length_squared();
+    if length_squared == 0.0 {
+        return offset.length();
+    }
+    let t = (-offset.dot(relative_motion) / length_squared).clamp(0.0, 1.0);
+    (offset + relative_motion * t).length()
+}
+
 #[derive(Component, Debug)]
 pub struct Collider {
-    pub radius: f32,
+    pub shape: ColliderShape,
     pub colliding_entities: Vec<Entity>,
 }
 
 impl Collider {
     pub fn new(radius: f32) -> Self {
+        Self::from_shape(ColliderShape::Sphere { radius })
+    }
+
+    pub fn from_shape(shape: ColliderShape) -> Self {
         Self {
-            radius,
+            shape,
             colliding_entities: vec![],
         }
     }
 }
 
+/// Replaces the [`Collider`] shape with a box around the entity's meshes once its scene has spawned.
+#[derive(Component, Debug)]
+pub struct FitColliderToMesh;
+
+/// Collision filtering: two colliders interact only if each one's `member` bits overlap the
+/// other's `filter` bits. Colliders without it are on every layer and accept every layer.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CollisionLayers {
+    pub member: u32,
+    pub filter: u32,
+}
+
+impl CollisionLayers {
+    pub const fn new(member: u32, filter: u32) -> Self {
+        Self { member, filter }
+    }
+
+    pub fn interacts_with(&self, other: &CollisionLayers) -> bool {
+        self.member & other.filter != 0 && other.member & self.filter != 0
+    }
+}
+
+impl Default for CollisionLayers {
+    fn default() -> Self {
+        Self::new(u32::MAX, u32::MAX)
+    }
+}
+
+/// Marks colliders that can cross a whole collider in one step, such as missiles.
+///
+/// They are tested along the path travelled since the previous collision pass instead of only at
+/// their current position, so hits between steps still register.
+#[derive(Component, Debug, Default)]
+pub struct FastMover {
+    previous_translation: Option<Vec3>,
+}
+
+impl FastMover {
+    /// Forgets the previous position, e.g. after a teleport, so no path is swept to the new one.
+    pub fn reset(&mut self) {
+        self.previous_translation = None;
+    }
+}
+
+/// Damage dealt to whatever this entity starts touching.
 #[derive(Component, Debug)]
 pub struct CollisionDamage {
     pub value: f32,
+    pub kind: DamageKind,
 }
 
 impl CollisionDamage {
     pub fn new(value: f32) -> Self {
-        Self { value }
+        Self {
+            value,
+            kind: DamageKind::default(),
+        }
+    }
+
+    pub fn with_kind(mut self, kind: DamageKind) -> Self {
+        self.kind = kind;
+        self
     }
 }
 
+/// Contact between two colliders, reported once per pair with `entity_a < entity_b`.
 #[derive(Event, Debug)]
 pub struct CollisionEvent {
-    pub entity: Entity,
-    pub collided_entity: Entity,
+    pub entity_a: Entity,
+    pub entity_b: Entity,
+    pub phase: CollisionPhase,
 }
 
 impl CollisionEvent {
-    pub fn new(entity: Entity, collided_entity: Entity) -> Self {
+    pub fn new(entity_a: Entity, entity_b: Entity, phase: CollisionPhase) -> Self {
         Self {
-            entity,
-            collided_entity,
+            entity_a,
+            entity_b,
+            phase,
         }
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CollisionPhase {
+    /// The pair touched this tick but not the one before.
+    Started,
+    /// The pair is still touching.
+    Stay,
+    /// The pair stopped touching, possibly because one of them was despawned.
+    Ended,
+}
+
+/// Pairs that were touching at the last collision pass, in the order they were reported.
+#[derive(Resource, Debug, Default)]
+pub struct Contacts {
+    pairs: Vec<(Entity, Entity)>,
+}
+
+const GRID_CELL_SIZE: f32 = 8.0;
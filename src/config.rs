@@ -0,0 +1,607 @@
+use std::ops::Range;
+
+use bevy::asset::{AssetLoader, AsyncReadExt, io::Reader, LoadContext};
+use bevy::prelude::{
+    App, Asset, AssetApp, AssetEvent, Assets, AssetServer, Commands, EventReader, Handle, info,
+    Plugin, Res, ResMut, Resource, Startup, TypePath, Update, Vec3,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::arena::BoundsPolicy;
+use crate::camera::CameraMode;
+use crate::collider_shape::ColliderShape;
+use crate::health::Resistances;
+use crate::weapon::WeaponKind;
+
+/// Loads [`GameConfig`] from `assets/game.config.ron` and keeps the resource in sync with the file.
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameConfig>()
+            .init_asset::<GameConfig>()
+            .register_asset_loader(GameConfigLoader)
+            .add_systems(Startup, load_config)
+            .add_systems(Update, apply_config);
+    }
+}
+
+fn load_config(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(GameConfigHandle(asset_server.load(CONFIG_PATH)));
+}
+
+fn apply_config(
+    mut event_reader: EventReader<AssetEvent<GameConfig>>,
+    handle: Res<GameConfigHandle>,
+    configs: Res<Assets<GameConfig>>,
+    mut config: ResMut<GameConfig>,
+) {
+    for event in event_reader.read() {
+        if !event.is_loaded_with_dependencies(&handle.0) && !event.is_modified(&handle.0) {
+            continue;
+        }
+        if let Some(loaded) = configs.get(&handle.0) {
+            info!("Applying game config from {}", CONFIG_PATH);
+            *config = loaded.clone();
+        }
+    }
+}
+
+#[derive(Resource, Debug)]
+pub struct GameConfigHandle(pub Handle<GameConfig>);
+
+/// Gameplay tuning values. Defaults match the shipped `game.config.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, Debug)]
+pub struct GameConfig {
+    /// Seeds [`GameRng`](crate::rng::GameRng) when no `--seed` is given on the command line.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub asteroid: AsteroidConfig,
+    pub spaceship: SpaceshipConfig,
+    pub weapon: WeaponConfig,
+    pub score: ScoreConfig,
+    pub simulation: SimulationConfig,
+    pub arena: ArenaConfig,
+    pub camera: CameraConfig,
+    pub feedback: FeedbackConfig,
+    pub hud: HudConfig,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SimulationConfig {
+    /// Fixed simulation ticks per second; rendering interpolates between them.
+    pub tick_rate_hz: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ArenaConfig {
+    /// Distance past the visible edge before entities wrap or despawn.
+    pub margin: f32,
+    pub spaceship: BoundsPolicy,
+    pub asteroid: BoundsPolicy,
+    pub missile: BoundsPolicy,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CameraConfig {
+    /// Mode used at startup and whenever the config is reloaded.
+    pub mode: CameraMode,
+    /// How quickly the camera catches up with its target, per second.
+    pub smoothing: f32,
+    /// Asteroids closer than this to the spaceship count as threats.
+    pub threat_radius: f32,
+    /// Extra distance per threat, as a fraction of the default distance.
+    pub zoom_per_threat: f32,
+    /// Upper limit for the summed threat zoom.
+    pub max_zoom: f32,
+    pub chase_distance: f32,
+    pub chase_height: f32,
+    /// How far ahead of the spaceship the chase camera looks.
+    pub chase_look_ahead: f32,
+    pub free_fly_speed: f32,
+    /// Largest sideways offset, reached at full trauma.
+    pub shake_max_offset: f32,
+    pub shake_max_roll_degrees: f32,
+    /// How fast the shake noise moves, in noise cells per second.
+    pub shake_frequency: f32,
+    /// Trauma removed per second.
+    pub trauma_decay: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct FeedbackConfig {
+    /// Trauma added per full health bar the spaceship loses.
+    pub spaceship_hit_trauma: f32,
+    pub spaceship_death_trauma: f32,
+    /// Trauma added per size tier of a destroyed asteroid.
+    pub asteroid_death_trauma: f32,
+    pub hit_stop: HitStopConfig,
+}
+
+/// Briefly slows the game down on big impacts.
+#[derive(Deserialize, Clone, Debug)]
+pub struct HitStopConfig {
+    pub enabled: bool,
+    /// Real-time length of the slowdown.
+    pub seconds: f32,
+    /// Game speed during the slowdown, relative to normal.
+    pub time_scale: f32,
+    /// Smallest hit, as a fraction of the spaceship's max health, that triggers a hit-stop.
+    pub min_damage_fraction: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct HudConfig {
+    /// Shows health bars over damaged entities other than the spaceship.
+    pub world_health_bars: bool,
+    /// How long a bar takes to fade out once its entity is back at full health.
+    pub health_bar_fade_seconds: f32,
+    /// World distance between an entity's collider and its health bar.
+    pub health_bar_offset: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ScoreConfig {
+    pub asteroid_points: u32,
+    pub combo_window_seconds: f32,
+    pub max_combo_multiplier: u32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AsteroidConfig {
+    pub velocity_scalar: f32,
+    pub acceleration_scalar: f32,
+    pub spawn_range_x: Range<f32>,
+    pub spawn_range_z: Range<f32>,
+    pub spawn_time_seconds: f32,
+    pub rotation_speed: f32,
+    pub health: f32,
+    pub collision_damage: f32,
+    pub size: u32,
+    pub min_size: u32,
+    pub min_fragments: u32,
+    pub max_fragments: u32,
+    pub fragment_scale: f32,
+    pub fragment_scatter_speed: f32,
+    pub wave_seconds: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct SpaceshipConfig {
+    pub translation_speed: f32,
+    pub rotation_speed: f32,
+    pub roll_speed: f32,
+    pub health: f32,
+    /// Health restored per second.
+    pub health_regeneration: f32,
+    /// Flat damage reduction per hit.
+    pub armor: f32,
+    pub resistances: Resistances,
+    pub collision_damage: f32,
+    pub missile_speed: f32,
+    pub missile_forward_spawn_range: f32,
+    pub missile_health: f32,
+    pub missile_collision_damage: f32,
+    pub shield: ShieldConfig,
+    pub lives: u32,
+    pub invulnerability_seconds: f32,
+    pub blink_interval_seconds: f32,
+    pub flight_model: FlightModel,
+    pub newtonian: NewtonianConfig,
+    pub collider: ColliderShape,
+    /// Replaces `collider` with a box fitted to the spaceship model once it has loaded.
+    pub fit_collider_to_mesh: bool,
+}
+
+/// How player input moves the spaceship.
+#[derive(Deserialize, Clone, Copy, Eq, PartialEq, Default, Debug)]
+pub enum FlightModel {
+    /// Velocity follows the thrust input directly; the ship stops as soon as it is released.
+    #[default]
+    Arcade,
+    /// Thrust accelerates the ship, which keeps its momentum until drag slows it down.
+    Newtonian,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct NewtonianConfig {
+    pub thrust_acceleration: f32,
+    /// Fraction of velocity lost per second, applied exponentially.
+    pub drag: f32,
+    pub max_speed: f32,
+    pub angular_velocity: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ShieldConfig {
+    pub max_energy: f32,
+    pub duration_seconds: f32,
+    pub recharge_rate: f32,
+    pub cooldown_seconds: f32,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WeaponConfig {
+    pub max_heat: f32,
+    pub heat_dissipation: f32,
+    pub single_shot: WeaponStats,
+    pub spread: WeaponStats,
+    pub rapid: WeaponStats,
+}
+
+impl WeaponConfig {
+    pub fn stats(&self, kind: WeaponKind) -> &WeaponStats {
+        match kind {
+            WeaponKind::SingleShot => &self.single_shot,
+            WeaponKind::Spread => &self.spread,
+            WeaponKind::Rapid => &self.rapid,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct WeaponStats {
+    pub cooldown_seconds: f32,
+    pub heat_per_shot: f32,
+    pub projectile_count: u32,
+    pub spread_degrees: f32,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            asteroid: AsteroidConfig {
+                velocity_scalar: 5.0,
+                acceleration_scalar: 1.0,
+                spawn_range_x: -25.0..25.0,
+                spawn_range_z: 0.0..25.0,
+                spawn_time_seconds: 1.0,
+                rotation_speed: 2.0,
+                health: 50.0,
+                collision_damage: 35.0,
+                size: 3,
+                min_size: 1,
+                min_fragments: 2,
+                max_fragments: 3,
+                fragment_scale: 0.6,
+                fragment_scatter_speed: 4.0,
+                wave_seconds: 30.0,
+            },
+            spaceship: SpaceshipConfig {
+                translation_speed: 25.0,
+                rotation_speed: 2.5,
+                roll_speed: 2.5,
+                health: 100.0,
+                health_regeneration: 0.0,
+                armor: 0.0,
+                resistances: Resistances::default(),
+                collision_damage: 100.0,
+                missile_speed: 50.0,
+                missile_forward_spawn_range: 10.0,
+                missile_health: 1.0,
+                missile_collision_damage: 10.0,
+                shield: ShieldConfig {
+                    max_energy: 50.0,
+                    duration_seconds: 3.0,
+                    recharge_rate: 10.0,
+                    cooldown_seconds: 2.0,
+                },
+                lives: 3,
+                invulnerability_seconds: 3.0,
+                blink_interval_seconds: 0.15,
+                flight_model: FlightModel::Arcade,
+                newtonian: NewtonianConfig {
+                    thrust_acceleration: 40.0,
+                    drag: 0.5,
+                    max_speed: 35.0,
+                    angular_velocity: 2.5,
+                },
+                collider: ColliderShape::Capsule {
+                    start: Vec3::new(0.0, 0.0, -2.0),
+                    end: Vec3::new(0.0, 0.0, 2.0),
+                    radius: 1.5,
+                },
+                fit_collider_to_mesh: true,
+            },
+            weapon: WeaponConfig {
+                max_heat: 100.0,
+                heat_dissipation: 35.0,
+                single_shot: WeaponStats {
+                    cooldown_seconds: 0.25,
+                    heat_per_shot: 8.0,
+                    projectile_count: 1,
+                    spread_degrees: 0.0,
+                },
+                spread: WeaponStats {
+                    cooldown_seconds: 0.6,
+                    heat_per_shot: 20.0,
+                    projectile_count: 5,
+                    spread_degrees: 40.0,
+                },
+                rapid: WeaponStats {
+                    cooldown_seconds: 0.08,
+                    heat_per_shot: 5.0,
+                    projectile_count: 1,
+                    spread_degrees: 0.0,
+                },
+            },
+            score: ScoreConfig {
+                asteroid_points: 20,
+                combo_window_seconds: 1.5,
+                max_combo_multiplier: 8,
+            },
+            simulation: SimulationConfig { tick_rate_hz: 60.0 },
+            arena: ArenaConfig {
+                margin: 5.0,
+                spaceship: BoundsPolicy::Wrap,
+                asteroid: BoundsPolicy::Despawn,
+                missile: BoundsPolicy::Despawn,
+            },
+            camera: CameraConfig {
+                mode: CameraMode::TopDown,
+                smoothing: 4.0,
+                threat_radius: 20.0,
+                zoom_per_threat: 0.05,
+                max_zoom: 0.4,
+                chase_distance: 25.0,
+                chase_height: 12.0,
+                chase_look_ahead: 15.0,
+                free_fly_speed: 40.0,
+                shake_max_offset: 3.0,
+                shake_max_roll_degrees: 4.0,
+                shake_frequency: 15.0,
+                trauma_decay: 1.2,
+            },
+            feedback: FeedbackConfig {
+                spaceship_hit_trauma: 1.5,
+                spaceship_death_trauma: 0.8,
+                asteroid_death_trauma: 0.08,
+                hit_stop: HitStopConfig {
+                    enabled: true,
+                    seconds: 0.08,
+                    time_scale: 0.05,
+                    min_damage_fraction: 0.3,
+                },
+            },
+            hud: HudConfig {
+                world_health_bars: true,
+                health_bar_fade_seconds: 0.5,
+                health_bar_offset: 1.0,
+            },
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn validate(&self) -> Result<(), GameConfigError> {
+        let asteroid = &self.asteroid;
+        let spaceship = &self.spaceship;
+        positive("asteroid.spawn_time_seconds", asteroid.spawn_time_seconds)?;
+        positive("asteroid.wave_seconds", asteroid.wave_seconds)?;
+        positive("asteroid.health", asteroid.health)?;
+        positive("asteroid.fragment_scale", asteroid.fragment_scale)?;
+        non_negative(
+            "asteroid.fragment_scatter_speed",
+            asteroid.fragment_scatter_speed,
+        )?;
+        if asteroid.min_size == 0 || asteroid.size < asteroid.min_size {
+            return Err(GameConfigError::InvalidSizes {
+                min_size: asteroid.min_size,
+                size: asteroid.size,
+            });
+        }
+        if asteroid.max_fragments < asteroid.min_fragments {
+            return Err(GameConfigError::InvalidFragments {
+                min: asteroid.min_fragments,
+                max: asteroid.max_fragments,
+            });
+        }
+        positive("spaceship.health", spaceship.health)?;
+        non_negative(
+            "spaceship.health_regeneration",
+            spaceship.health_regeneration,
+        )?;
+        non_negative("spaceship.armor", spaceship.armor)?;
+        positive("spaceship.collider", spaceship.collider.bounding_radius())?;
+        positive("spaceship.missile_speed", spaceship.missile_speed)?;
+        positive("spaceship.missile_health", spaceship.missile_health)?;
+        non_negative("asteroid.velocity_scalar", asteroid.velocity_scalar)?;
+        non_negative("asteroid.acceleration_scalar", asteroid.acceleration_scalar)?;
+        non_negative("asteroid.collision_damage", asteroid.collision_damage)?;
+        non_negative("spaceship.translation_speed", spaceship.translation_speed)?;
+        non_negative("spaceship.collision_damage", spaceship.collision_damage)?;
+        non_negative(
+            "spaceship.missile_collision_damage",
+            spaceship.missile_collision_damage,
+        )?;
+        positive("spaceship.lives", spaceship.lives as f32)?;
+        non_negative(
+            "spaceship.invulnerability_seconds",
+            spaceship.invulnerability_seconds,
+        )?;
+        positive(
+            "spaceship.blink_interval_seconds",
+            spaceship.blink_interval_seconds,
+        )?;
+        non_negative(
+            "spaceship.newtonian.thrust_acceleration",
+            spaceship.newtonian.thrust_acceleration,
+        )?;
+        non_negative("spaceship.newtonian.drag", spaceship.newtonian.drag)?;
+        positive(
+            "spaceship.newtonian.max_speed",
+            spaceship.newtonian.max_speed,
+        )?;
+        non_negative(
+            "spaceship.newtonian.angular_velocity",
+            spaceship.newtonian.angular_velocity,
+        )?;
+        positive("spaceship.shield.max_energy", spaceship.shield.max_energy)?;
+        positive(
+            "spaceship.shield.duration_seconds",
+            spaceship.shield.duration_seconds,
+        )?;
+        non_negative(
+            "spaceship.shield.recharge_rate",
+            spaceship.shield.recharge_rate,
+        )?;
+        non_negative(
+            "spaceship.shield.cooldown_seconds",
+            spaceship.shield.cooldown_seconds,
+        )?;
+        positive("weapon.max_heat", self.weapon.max_heat)?;
+        non_negative("weapon.heat_dissipation", self.weapon.heat_dissipation)?;
+        for (name, stats) in [
+            ("weapon.single_shot", &self.weapon.single_shot),
+            ("weapon.spread", &self.weapon.spread),
+            ("weapon.rapid", &self.weapon.rapid),
+        ] {
+            stats.validate(name)?;
+        }
+        positive(
+            "score.combo_window_seconds",
+            self.score.combo_window_seconds,
+        )?;
+        positive("simulation.tick_rate_hz", self.simulation.tick_rate_hz)?;
+        non_negative("arena.margin", self.arena.margin)?;
+        positive("camera.smoothing", self.camera.smoothing)?;
+        non_negative("camera.threat_radius", self.camera.threat_radius)?;
+        non_negative("camera.zoom_per_threat", self.camera.zoom_per_threat)?;
+        non_negative("camera.max_zoom", self.camera.max_zoom)?;
+        non_negative("camera.free_fly_speed", self.camera.free_fly_speed)?;
+        non_negative("camera.shake_max_offset", self.camera.shake_max_offset)?;
+        non_negative(
+            "camera.shake_max_roll_degrees",
+            self.camera.shake_max_roll_degrees,
+        )?;
+        non_negative("camera.shake_frequency", self.camera.shake_frequency)?;
+        non_negative("camera.trauma_decay", self.camera.trauma_decay)?;
+        let feedback = &self.feedback;
+        non_negative(
+            "feedback.spaceship_hit_trauma",
+            feedback.spaceship_hit_trauma,
+        )?;
+        non_negative(
+            "feedback.spaceship_death_trauma",
+            feedback.spaceship_death_trauma,
+        )?;
+        non_negative(
+            "feedback.asteroid_death_trauma",
+            feedback.asteroid_death_trauma,
+        )?;
+        non_negative("feedback.hit_stop.seconds", feedback.hit_stop.seconds)?;
+        positive("feedback.hit_stop.time_scale", feedback.hit_stop.time_scale)?;
+        non_negative(
+            "feedback.hit_stop.min_damage_fraction",
+            feedback.hit_stop.min_damage_fraction,
+        )?;
+        positive(
+            "hud.health_bar_fade_seconds",
+            self.hud.health_bar_fade_seconds,
+        )?;
+        non_empty("asteroid.spawn_range_x", &asteroid.spawn_range_x)?;
+        non_empty("asteroid.spawn_range_z", &asteroid.spawn_range_z)?;
+        Ok(())
+    }
+}
+
+impl WeaponStats {
+    fn validate(&self, field: &'static str) -> Result<(), GameConfigError> {
+        if self.projectile_count == 0
+            || self.cooldown_seconds < 0.0
+            || self.heat_per_shot < 0.0
+            || self.spread_degrees < 0.0
+        {
+            return Err(GameConfigError::InvalidWeapon { field });
+        }
+        Ok(())
+    }
+}
+
+fn positive(field: &'static str, value: f32) -> Result<(), GameConfigError> {
+    if value > 0.0 {
+        Ok(())
+    } else {
+        Err(GameConfigError::NotPositive { field, value })
+    }
+}
+
+fn non_negative(field: &'static str, value: f32) -> Result<(), GameConfigError> {
+    if value >= 0.0 {
+        Ok(())
+    } else {
+        Err(GameConfigError::Negative { field, value })
+    }
+}
+
+fn non_empty(field: &'static str, range: &Range<f32>) -> Result<(), GameConfigError> {
+    if range.start < range.end {
+        Ok(())
+    } else {
+        Err(GameConfigError::EmptyRange {
+            field,
+            start: range.start,
+            end: range.end,
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GameConfigError {
+    #[error("{field} must be greater than zero, got {value}")]
+    NotPositive { field: &'static str, value: f32 },
+    #[error("{field} must not be negative, got {value}")]
+    Negative { field: &'static str, value: f32 },
+    #[error("asteroid.max_fragments ({max}) must not be less than asteroid.min_fragments ({min})")]
+    InvalidFragments { min: u32, max: u32 },
+    #[error("asteroid.min_size ({min_size}) must be between 1 and asteroid.size ({size})")]
+    InvalidSizes { min_size: u32, size: u32 },
+    #[error("{field} needs at least one projectile and non-negative cooldown, heat and spread")]
+    InvalidWeapon { field: &'static str },
+    #[error("{field} must be a non-empty range, got {start}..{end}")]
+    EmptyRange {
+        field: &'static str,
+        start: f32,
+        end: f32,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum GameConfigLoaderError {
+    #[error("could not read game config: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse game config: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("invalid game config: {0}")]
+    Invalid(#[from] GameConfigError),
+}
+
+#[derive(Default)]
+struct GameConfigLoader;
+
+impl AssetLoader for GameConfigLoader {
+    type Asset = GameConfig;
+    type Settings = ();
+    type Error = GameConfigLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let config: GameConfig = ron::de::from_bytes(&bytes)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["config.ron"]
+    }
+}
+
+const CONFIG_PATH: &str = "game.config.ron";
@@ -1,4 +1,4 @@
-use bevy::prelude::{App, Entity, info, IntoSystemConfigs, Plugin, Query, Transform, Update};
+use bevy::prelude::{App, Entity, FixedUpdate, info, IntoSystemConfigs, Plugin, Query, Transform};
 
 use crate::movement::Velocity;
 use crate::schedule::InGameSet;
@@ -7,7 +7,7 @@ pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, print_position.after(InGameSet::EntityUpdates));
+        app.add_systems(FixedUpdate, print_position.after(InGameSet::EntityUpdates));
     }
 }
 
@@ -1,48 +1,78 @@
 use bevy::app::App;
 use bevy::prelude::{
-    Commands, Component, DespawnRecursiveExt, Entity, GlobalTransform, IntoSystemConfigs, Plugin,
-    Query, Update, Vec3, With,
+    Commands, DespawnRecursiveExt, Entity, Event, EventReader, EventWriter, FixedUpdate,
+    GlobalTransform, Has, IntoSystemConfigs, Plugin, Query, Vec3,
 };
+use bevy::utils::HashMap;
 
 use crate::asteroid::Asteroid;
-use crate::health::Health;
+use crate::health::{EntityDestroyed, Health};
 use crate::schedule::InGameSet;
-use crate::spaceship::SpaceshipMissile;
+use crate::spaceship::{Spaceship, SpaceshipMissile};
 
 pub struct DespawnPlugin;
 
 impl Plugin for DespawnPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                despawn_far_away_components::<Asteroid>,
-                despawn_far_away_components::<SpaceshipMissile>,
-                despawn_dead_entities,
-            )
-                .in_set(InGameSet::DespawnEntities),
+        app.add_event::<EntityDied>().add_systems(
+            FixedUpdate,
+            despawn_dead_entities.in_set(InGameSet::DespawnEntities),
         );
     }
 }
 
-fn despawn_far_away_components<T: Component>(
+/// Sends [`EntityDied`] for every entity out of health, then despawns it.
+///
+/// The despawn is deferred, so systems ordered after this one can still read the dead entity.
+pub fn despawn_dead_entities(
     mut commands: Commands,
-    query: Query<(Entity, &GlobalTransform), With<T>>,
+    mut event_reader: EventReader<EntityDestroyed>,
+    mut event_writer: EventWriter<EntityDied>,
+    query: Query<(Entity, &Health, &GlobalTransform)>,
+    kind_query: Query<(Option<&Asteroid>, Has<Spaceship>, Has<SpaceshipMissile>)>,
 ) {
-    for (entity, global_transform) in query.iter() {
-        let distance = global_transform.translation().distance(Vec3::ZERO);
-        if DESPAWN_DISTANCE_THRESHOLD <= distance {
-            commands.entity(entity).despawn_recursive();
+    let killers: HashMap<Entity, Entity> = event_reader
+        .read()
+        .map(|event| (event.entity, event.killer))
+        .collect();
+
+    for (entity, health, global_transform) in query.iter() {
+        if 0.0 < health.value {
+            continue;
         }
-    }
-}
 
-fn despawn_dead_entities(mut commands: Commands, query: Query<(Entity, &Health)>) {
-    for (entity, health) in query.iter() {
-        if health.value <= 0.0 {
-            commands.entity(entity).despawn_recursive();
+        let kind = match kind_query.get(entity) {
+            Ok((Some(asteroid), _, _)) => EntityKind::Asteroid {
+                size: asteroid.size,
+            },
+            Ok((_, true, _)) => EntityKind::Spaceship,
+            Ok((_, _, true)) => EntityKind::SpaceshipMissile,
+            _ => EntityKind::Other,
         };
+        event_writer.send(EntityDied {
+            entity,
+            kind,
+            position: global_transform.translation(),
+            killer: killers.get(&entity).copied(),
+        });
+        commands.entity(entity).despawn_recursive();
     }
 }
 
-const DESPAWN_DISTANCE_THRESHOLD: f32 = 100.0;
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EntityKind {
+    Spaceship,
+    SpaceshipMissile,
+    Asteroid { size: u32 },
+    Other,
+}
+
+/// Sent just before an entity that ran out of health is despawned.
+#[derive(Event, Debug)]
+pub struct EntityDied {
+    pub entity: Entity,
+    pub kind: EntityKind,
+    pub position: Vec3,
+    /// Entity that dealt the killing blow, if the death came from damage.
+    pub killer: Option<Entity>,
+}
@@ -0,0 +1,163 @@
+use bevy::prelude::{
+    App, Assets, Color, Commands, Component, Cuboid, default, DespawnRecursiveExt, Entity,
+    EventReader, Handle, in_state, IntoSystemConfigs, LinearRgba, Mesh, PbrBundle, Plugin,
+    PointLight, PointLightBundle, Query, Res, ResMut, Resource, StandardMaterial, Startup,
+    StateScoped, Time, Timer, TimerMode, Transform, Update, Vec3,
+};
+use rand::Rng;
+
+use crate::despawn::{EntityDied, EntityKind};
+use crate::state::{GameState, PauseState};
+
+/// Debris and light flashes for entities that die.
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_effect_assets).add_systems(
+            Update,
+            (
+                spawn_death_effects,
+                (update_debris, update_flashes).run_if(in_state(PauseState::Running)),
+            )
+                .chain(),
+        );
+    }
+}
+
+fn load_effect_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(EffectAssets {
+        debris_mesh: meshes.add(Cuboid::from_size(Vec3::splat(DEBRIS_SIZE))),
+        debris_material: materials.add(StandardMaterial {
+            base_color: DEBRIS_COLOR,
+            emissive: DEBRIS_EMISSIVE,
+            ..default()
+        }),
+    });
+}
+
+fn spawn_death_effects(
+    mut commands: Commands,
+    mut event_reader: EventReader<EntityDied>,
+    effect_assets: Res<EffectAssets>,
+) {
+    // Purely cosmetic, so it must not draw from the seeded gameplay RNG.
+    let mut rng = rand::thread_rng();
+    for event in event_reader.read() {
+        let (debris_count, flash_intensity) = match event.kind {
+            EntityKind::Spaceship => (SPACESHIP_DEBRIS_COUNT, SPACESHIP_FLASH_INTENSITY),
+            EntityKind::Asteroid { size } => (
+                ASTEROID_DEBRIS_PER_SIZE * size,
+                ASTEROID_FLASH_INTENSITY * size as f32,
+            ),
+            EntityKind::SpaceshipMissile | EntityKind::Other => continue,
+        };
+
+        for _ in 0..debris_count {
+            let direction = Vec3::new(rng.gen_range(-1.0..1.0), 0.0, rng.gen_range(-1.0..1.0))
+                .normalize_or_zero();
+            commands.spawn((
+                Debris {
+                    velocity: direction * rng.gen_range(DEBRIS_MIN_SPEED..DEBRIS_MAX_SPEED),
+                    lifetime: Timer::from_seconds(DEBRIS_LIFETIME_SECONDS, TimerMode::Once),
+                },
+                StateScoped(GameState::InGame),
+                PbrBundle {
+                    mesh: effect_assets.debris_mesh.clone(),
+                    material: effect_assets.debris_material.clone(),
+                    transform: Transform::from_translation(event.position),
+                    ..default()
+                },
+            ));
+        }
+
+        commands.spawn((
+            Flash {
+                intensity: flash_intensity,
+                lifetime: Timer::from_seconds(FLASH_LIFETIME_SECONDS, TimerMode::Once),
+            },
+            StateScoped(GameState::InGame),
+            PointLightBundle {
+                point_light: PointLight {
+                    color: FLASH_COLOR,
+                    intensity: flash_intensity,
+                    range: FLASH_RANGE,
+                    ..default()
+                },
+                transform: Transform::from_translation(event.position + FLASH_OFFSET),
+                ..default()
+            },
+        ));
+    }
+}
+
+fn update_debris(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Debris, &mut Transform)>,
+    time: Res<Time>,
+) {
+    for (entity, mut debris, mut transform) in query.iter_mut() {
+        debris.lifetime.tick(time.delta());
+        if debris.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        transform.translation += debris.velocity * time.delta_seconds();
+        transform.scale = Vec3::splat(debris.lifetime.fraction_remaining());
+    }
+}
+
+fn update_flashes(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Flash, &mut PointLight)>,
+    time: Res<Time>,
+) {
+    for (entity, mut flash, mut point_light) in query.iter_mut() {
+        flash.lifetime.tick(time.delta());
+        if flash.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        point_light.intensity = flash.intensity * flash.lifetime.fraction_remaining();
+    }
+}
+
+#[derive(Resource, Debug)]
+struct EffectAssets {
+    debris_mesh: Handle<Mesh>,
+    debris_material: Handle<StandardMaterial>,
+}
+
+/// Fragment thrown out by a death, shrinking until its lifetime runs out.
+#[derive(Component, Debug)]
+struct Debris {
+    velocity: Vec3,
+    lifetime: Timer,
+}
+
+/// Point light that fades out over its lifetime.
+#[derive(Component, Debug)]
+struct Flash {
+    intensity: f32,
+    lifetime: Timer,
+}
+
+const DEBRIS_SIZE: f32 = 0.4;
+const DEBRIS_COLOR: Color = Color::srgb(0.45, 0.4, 0.35);
+const DEBRIS_EMISSIVE: LinearRgba = LinearRgba::rgb(2.0, 0.8, 0.2);
+const DEBRIS_MIN_SPEED: f32 = 4.0;
+const DEBRIS_MAX_SPEED: f32 = 14.0;
+const DEBRIS_LIFETIME_SECONDS: f32 = 0.8;
+const ASTEROID_DEBRIS_PER_SIZE: u32 = 4;
+const SPACESHIP_DEBRIS_COUNT: u32 = 24;
+const FLASH_COLOR: Color = Color::srgb(1.0, 0.7, 0.3);
+const FLASH_RANGE: f32 = 40.0;
+/// Lifts the light above the play plane so it lights the tops of nearby models.
+const FLASH_OFFSET: Vec3 = Vec3::new(0.0, 5.0, 0.0);
+const FLASH_LIFETIME_SECONDS: f32 = 0.25;
+const ASTEROID_FLASH_INTENSITY: f32 = 1_500_000.0;
+const SPACESHIP_FLASH_INTENSITY: f32 = 10_000_000.0;
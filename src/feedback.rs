@@ -0,0 +1,104 @@
+use bevy::prelude::{
+    App, Changed, EventReader, IntoSystemConfigs, Local, Plugin, Query, Real, Res, ResMut, Resource,
+    Time, Timer, TimerMode, Update, Virtual, With,
+};
+
+use crate::camera::CameraShake;
+use crate::config::{GameConfig, HitStopConfig};
+use crate::despawn::{EntityDied, EntityKind};
+use crate::health::Health;
+use crate::spaceship::Spaceship;
+
+/// Shakes the camera and briefly slows the game down when the spaceship is hit or something dies.
+pub struct FeedbackPlugin;
+
+impl Plugin for FeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HitStop>().add_systems(
+            Update,
+            (spaceship_hit_feedback, death_feedback, update_hit_stop).chain(),
+        );
+    }
+}
+
+fn spaceship_hit_feedback(
+    query: Query<&Health, (With<Spaceship>, Changed<Health>)>,
+    mut previous_health: Local<Option<f32>>,
+    mut shake: ResMut<CameraShake>,
+    mut hit_stop: ResMut<HitStop>,
+    config: Res<GameConfig>,
+) {
+    let Ok(health) = query.get_single() else {
+        return;
+    };
+    // A freshly spawned spaceship has more health than the last one had left, so it never counts
+    // as a hit.
+    if let Some(previous) = previous_health.replace(health.value) {
+        if health.value < previous {
+            let config = &config.feedback;
+            let lost_fraction = (previous - health.value) / health.max;
+            shake.add_trauma(lost_fraction * config.spaceship_hit_trauma);
+            if config.hit_stop.min_damage_fraction <= lost_fraction {
+                hit_stop.trigger(&config.hit_stop);
+            }
+        }
+    }
+}
+
+fn death_feedback(
+    mut event_reader: EventReader<EntityDied>,
+    mut shake: ResMut<CameraShake>,
+    mut hit_stop: ResMut<HitStop>,
+    config: Res<GameConfig>,
+) {
+    let config = &config.feedback;
+    for event in event_reader.read() {
+        match event.kind {
+            EntityKind::Spaceship => {
+                shake.add_trauma(config.spaceship_death_trauma);
+                hit_stop.trigger(&config.hit_stop);
+            }
+            EntityKind::Asteroid { size } => {
+                shake.add_trauma(size as f32 * config.asteroid_death_trauma);
+            }
+            EntityKind::SpaceshipMissile | EntityKind::Other => {}
+        }
+    }
+}
+
+fn update_hit_stop(
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+) {
+    let time_scale = hit_stop.time_scale;
+    let Some(timer) = hit_stop.timer.as_mut() else {
+        return;
+    };
+
+    // Counted in real time, since virtual time is what it slows down.
+    timer.tick(real_time.delta());
+    if timer.finished() {
+        hit_stop.timer = None;
+        virtual_time.set_relative_speed(1.0);
+    } else {
+        virtual_time.set_relative_speed(time_scale);
+    }
+}
+
+/// Slows virtual time, and with it the simulation, for a moment after a big impact.
+#[derive(Resource, Debug, Default)]
+pub struct HitStop {
+    timer: Option<Timer>,
+    time_scale: f32,
+}
+
+impl HitStop {
+    pub fn trigger(&mut self, config: &HitStopConfig) {
+        if !config.enabled {
+            return;
+        }
+        self.timer = Some(Timer::from_seconds(config.seconds, TimerMode::Once));
+        self.time_scale = config.time_scale;
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::app::{PluginGroup, PluginGroupBuilder};
+use bevy::hierarchy::HierarchyPlugin;
+use bevy::input::InputPlugin;
+use bevy::prelude::{App, AppExtStates, MinimalPlugins, Plugin};
+use bevy::state::app::StatesPlugin;
+use bevy::transform::TransformPlugin;
+
+use crate::action::ActionPlugin;
+use crate::arena::ArenaPlugin;
+use crate::asset_loader::SceneAssets;
+use crate::asteroid::AsteroidPlugin;
+use crate::collision_detection::CollisionDetectionPlugin;
+use crate::config::GameConfig;
+use crate::despawn::DespawnPlugin;
+use crate::health::HealthPlugin;
+use crate::movement::MovementPlugin;
+use crate::rng::RngPlugin;
+use crate::schedule::SchedulePlugin;
+use crate::score::ScorePlugin;
+use crate::spaceship::SpaceshipPlugin;
+use crate::state::{GameState, StatePlugin};
+use crate::weapon::WeaponPlugin;
+
+/// Runs the gameplay logic on top of [`MinimalPlugins`], without a window, renderer or GPU.
+///
+/// Meant for CI and integration tests that step the [`App`] frame by frame.
+pub struct HeadlessGamePlugin;
+
+impl PluginGroup for HeadlessGamePlugin {
+    fn build(self) -> PluginGroupBuilder {
+        MinimalPlugins
+            .build()
+            .add(StatesPlugin)
+            .add(TransformPlugin)
+            .add(HierarchyPlugin)
+            .add(InputPlugin)
+            // core
+            .add(RngPlugin::default())
+            .add(ActionPlugin)
+            .add(SchedulePlugin)
+            .add(DespawnPlugin)
+            // game logic
+            .add(StatePlugin)
+            .add(MovementPlugin)
+            .add(ArenaPlugin)
+            .add(CollisionDetectionPlugin)
+            .add(HealthPlugin)
+            .add(ScorePlugin)
+            // components
+            .add(SpaceshipPlugin)
+            .add(AsteroidPlugin)
+            .add(WeaponPlugin)
+            .add(HeadlessAssetsPlugin)
+    }
+}
+
+/// Stand-in for the asset loaders that provides empty scene handles and the default config.
+///
+/// There is nothing to load and no menu to click through, so the app starts in
+/// [`GameState::InGame`].
+pub struct HeadlessAssetsPlugin;
+
+impl Plugin for HeadlessAssetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SceneAssets>()
+            .init_resource::<GameConfig>()
+            .insert_state(GameState::InGame);
+    }
+}
@@ -1,12 +1,187 @@
-use bevy::prelude::Component;
+use bevy::prelude::{
+    App, Component, Entity, Event, EventReader, EventWriter, FixedUpdate, IntoSystemConfigs, Plugin,
+    Query, Res, Time, Timer, TimerMode, With,
+};
+use serde::Deserialize;
+
+use crate::schedule::InGameSet;
+use crate::shield::{ShieldGenerator, SpaceshipShield};
+
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DamageEvent>()
+            .add_event::<EntityDestroyed>()
+            .add_systems(
+                FixedUpdate,
+                (regenerate_health, apply_damage)
+                    .chain()
+                    .in_set(InGameSet::EntityUpdates),
+            );
+    }
+}
+
+fn regenerate_health(mut query: Query<&mut Health>, time: Res<Time>) {
+    for mut health in query.iter_mut() {
+        // Dead entities stay dead, and full ones are left alone so `Changed<Health>` stays quiet.
+        if health.regeneration <= 0.0 || health.value <= 0.0 || health.max <= health.value {
+            continue;
+        }
+        health.value = (health.value + health.regeneration * time.delta_seconds()).min(health.max);
+    }
+}
+
+/// Applies [`DamageEvent`]s through resistances, armor and an active shield, in that order.
+pub fn apply_damage(
+    mut event_reader: EventReader<DamageEvent>,
+    mut event_writer: EventWriter<EntityDestroyed>,
+    mut health_query: Query<(&mut Health, Option<&Resistances>, Option<&Armor>)>,
+    mut shield_query: Query<&mut ShieldGenerator, With<SpaceshipShield>>,
+    invulnerable_query: Query<(), With<Invulnerable>>,
+) {
+    for event in event_reader.read() {
+        if invulnerable_query.contains(event.target) {
+            continue;
+        }
+        let Ok((mut health, resistances, armor)) = health_query.get_mut(event.target) else {
+            continue;
+        };
+
+        let mut damage = event.amount;
+        if let Some(resistances) = resistances {
+            damage *= resistances.multiplier(event.kind);
+        }
+        if let Some(armor) = armor {
+            damage = (damage - armor.value).max(0.0);
+        }
+        if let Ok(mut shield_generator) = shield_query.get_mut(event.target) {
+            damage = shield_generator.absorb(damage);
+        }
+
+        let was_alive = 0.0 < health.value;
+        let remaining = health.value - damage;
+        health.value = remaining.clamp(0.0, health.max);
+        if was_alive && remaining <= 0.0 {
+            event_writer.send(EntityDestroyed::new(event.target, event.source, -remaining));
+        }
+    }
+}
 
 #[derive(Component, Debug)]
 pub struct Health {
+    /// Current health, kept within `0.0..=max`.
     pub value: f32,
+    pub max: f32,
+    /// Health restored per second while alive.
+    pub regeneration: f32,
 }
 
 impl Health {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            max: value,
+            regeneration: 0.0,
+        }
+    }
+
+    pub fn with_regeneration(mut self, regeneration: f32) -> Self {
+        self.regeneration = regeneration;
+        self
+    }
+}
+
+/// Ignores incoming damage, and deals no collision damage, until the timer runs out.
+#[derive(Component, Debug)]
+pub struct Invulnerable {
+    pub timer: Timer,
+}
+
+impl Invulnerable {
+    pub fn new(seconds: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum DamageKind {
+    #[default]
+    Kinetic,
+    Explosive,
+    Energy,
+}
+
+/// Flat reduction subtracted from every hit after resistances.
+#[derive(Component, Debug)]
+pub struct Armor {
+    pub value: f32,
+}
+
+impl Armor {
     pub fn new(value: f32) -> Self {
         Self { value }
     }
 }
+
+/// Fraction of each [`DamageKind`] that is ignored, from `0.0` (none) to `1.0` (immune).
+#[derive(Component, Deserialize, Clone, Default, Debug)]
+pub struct Resistances {
+    pub kinetic: f32,
+    pub explosive: f32,
+    pub energy: f32,
+}
+
+impl Resistances {
+    pub fn multiplier(&self, kind: DamageKind) -> f32 {
+        let resistance = match kind {
+            DamageKind::Kinetic => self.kinetic,
+            DamageKind::Explosive => self.explosive,
+            DamageKind::Energy => self.energy,
+        };
+        1.0 - resistance.clamp(0.0, 1.0)
+    }
+}
+
+/// Request to damage `target`, before any armor or resistances.
+#[derive(Event, Debug)]
+pub struct DamageEvent {
+    pub target: Entity,
+    /// Entity that dealt the damage.
+    pub source: Entity,
+    pub amount: f32,
+    pub kind: DamageKind,
+}
+
+impl DamageEvent {
+    pub fn new(target: Entity, source: Entity, amount: f32, kind: DamageKind) -> Self {
+        Self {
+            target,
+            source,
+            amount,
+            kind,
+        }
+    }
+}
+
+/// Sent when damage brings an entity's [`Health`] to zero.
+#[derive(Event, Debug)]
+pub struct EntityDestroyed {
+    pub entity: Entity,
+    /// Entity that dealt the killing blow.
+    pub killer: Entity,
+    /// Damage left over after health reached zero.
+    pub overkill: f32,
+}
+
+impl EntityDestroyed {
+    pub fn new(entity: Entity, killer: Entity, overkill: f32) -> Self {
+        Self {
+            entity,
+            killer,
+            overkill,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::color::Alpha;
+use bevy::prelude::{
+    App, BackgroundColor, BuildChildren, Camera, Color, Commands, Component, Condition, default,
+    DespawnRecursiveExt, DetectChanges, Entity, GlobalTransform, in_state, IntoSystemConfigs,
+    NodeBundle, Plugin, PositionType, PostUpdate, Query, Ref, Res, StateScoped, Style, Time,
+    TransformSystem, Update, Val, Visibility, Without,
+};
+use bevy::ui::UiSystem;
+use bevy::utils::HashSet;
+
+use crate::collision_detection::Collider;
+use crate::config::GameConfig;
+use crate::health::Health;
+use crate::spaceship::Spaceship;
+use crate::state::GameState;
+
+/// Draws a small health bar over every damaged entity except the spaceship, which has the HUD.
+///
+/// Bars are UI nodes placed where the camera sees their target, so they always face the screen.
+pub struct HealthBarPlugin;
+
+impl Plugin for HealthBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            spawn_world_health_bars
+                .run_if(in_state(GameState::InGame).and_then(world_health_bars_enabled)),
+        )
+        // Placed from this frame's global transforms, before the UI lays the bars out.
+        .add_systems(
+            PostUpdate,
+            update_world_health_bars
+                .after(TransformSystem::TransformPropagate)
+                .before(UiSystem::Layout)
+                .run_if(in_state(GameState::InGame)),
+        );
+    }
+}
+
+fn world_health_bars_enabled(config: Res<GameConfig>) -> bool {
+    config.hud.world_health_bars
+}
+
+fn spawn_world_health_bars(
+    mut commands: Commands,
+    target_query: Query<(Entity, Ref<Health>), Without<Spaceship>>,
+    bar_query: Query<&WorldHealthBar>,
+) {
+    let targets_with_bars: HashSet<Entity> = bar_query.iter().map(|bar| bar.target).collect();
+    for (target, health) in target_query.iter() {
+        if !health.is_changed() || health.max <= health.value || targets_with_bars.contains(&target)
+        {
+            continue;
+        }
+
+        let fill = commands
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                background_color: FILL_COLOR.into(),
+                ..default()
+            })
+            .id();
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    background_color: BACKGROUND_COLOR.into(),
+                    // Hidden until the first update has placed it.
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                WorldHealthBar {
+                    target,
+                    fill,
+                    opacity: 1.0,
+                },
+                StateScoped(GameState::InGame),
+            ))
+            .add_child(fill);
+    }
+}
+
+fn update_world_health_bars(
+    mut commands: Commands,
+    mut bar_query: Query<(
+        Entity,
+        &mut WorldHealthBar,
+        &mut Style,
+        &mut BackgroundColor,
+        &mut Visibility,
+    )>,
+    mut fill_query: Query<(&mut Style, &mut BackgroundColor), Without<WorldHealthBar>>,
+    target_query: Query<(&Health, &GlobalTransform, Option<&Collider>)>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    config: Res<GameConfig>,
+    time: Res<Time>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.get_single() else {
+        return;
+    };
+
+    for (bar_entity, mut bar, mut style, mut background_color, mut visibility) in
+        bar_query.iter_mut()
+    {
+        let Ok((health, target_transform, collider)) = target_query.get(bar.target) else {
+            commands.entity(bar_entity).despawn_recursive();
+            continue;
+        };
+        if !config.hud.world_health_bars {
+            commands.entity(bar_entity).despawn_recursive();
+            continue;
+        }
+
+        // Fades out once the target is back at full health, and reappears on the next hit.
+        if health.value < health.max {
+            bar.opacity = 1.0;
+        } else {
+            bar.opacity -= time.delta_seconds() / config.hud.health_bar_fade_seconds;
+            if bar.opacity <= 0.0 {
+                commands.entity(bar_entity).despawn_recursive();
+                continue;
+            }
+        }
+
+        // Anchored just past the target's collider, on the side that is up on screen.
+        let radius = collider.map_or(0.0, |collider| collider.shape.bounding_radius());
+        let anchor = target_transform.translation()
+            + camera_transform.up() * (radius + config.hud.health_bar_offset);
+        let Some(position) = camera.world_to_viewport(camera_transform, anchor) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        *visibility = Visibility::Inherited;
+        style.left = Val::Px(position.x - BAR_WIDTH / 2.0);
+        style.top = Val::Px(position.y - BAR_HEIGHT);
+        *background_color = BACKGROUND_COLOR
+            .with_alpha(BACKGROUND_ALPHA * bar.opacity)
+            .into();
+
+        if let Ok((mut fill_style, mut fill_color)) = fill_query.get_mut(bar.fill) {
+            fill_style.width = Val::Percent((health.value / health.max).clamp(0.0, 1.0) * 100.0);
+            *fill_color = FILL_COLOR.with_alpha(bar.opacity).into();
+        }
+    }
+}
+
+/// Screen-space health bar following `target`.
+#[derive(Component, Debug)]
+pub struct WorldHealthBar {
+    pub target: Entity,
+    /// Child node whose width shows the remaining health.
+    fill: Entity,
+    opacity: f32,
+}
+
+const BAR_WIDTH: f32 = 48.0;
+const BAR_HEIGHT: f32 = 6.0;
+const BACKGROUND_COLOR: Color = Color::srgb(0.1, 0.1, 0.1);
+const BACKGROUND_ALPHA: f32 = 0.6;
+const FILL_COLOR: Color = Color::srgb(0.95, 0.35, 0.25);
@@ -0,0 +1,302 @@
+use bevy::prelude::{
+    AlignItems, App, BackgroundColor, BuildChildren, Changed, ChildBuilder, Color, Commands,
+    Component, default, DetectChanges, Entity, FlexDirection, in_state, IntoSystemConfigs,
+    JustifyContent, NodeBundle, OnEnter, Plugin, PositionType, Query, Ref, Res, resource_changed,
+    StateScoped, Style, Text, TextBundle, TextStyle, UiRect, Update, Val, With,
+};
+
+use crate::asteroid::Wave;
+use crate::config::GameConfig;
+use crate::health::Health;
+use crate::score::Score;
+use crate::shield::{ShieldGenerator, SpaceshipShield};
+use crate::spaceship::{Lives, Spaceship};
+use crate::state::GameState;
+use crate::weapon::{Weapon, WeaponKind};
+
+/// Shows the spaceship's health, shield and weapon alongside score, lives and wave during a run.
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::InGame), spawn_hud)
+            .add_systems(
+                Update,
+                (
+                    update_health_bar,
+                    update_shield_bar,
+                    update_weapon_display,
+                    update_score_text.run_if(resource_changed::<Score>),
+                    update_lives_text.run_if(resource_changed::<Lives>),
+                    update_wave_text.run_if(resource_changed::<Wave>),
+                )
+                    .run_if(in_state(GameState::InGame)),
+            );
+    }
+}
+
+fn spawn_hud(
+    mut commands: Commands,
+    score: Res<Score>,
+    lives: Res<Lives>,
+    wave: Res<Wave>,
+    config: Res<GameConfig>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(HUD_PADDING)),
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                },
+                ..default()
+            },
+            StateScoped(GameState::InGame),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(column_bundle(AlignItems::Start))
+                .with_children(|parent| {
+                    spawn_bar(parent, "Hull", HEALTH_COLOR, HealthBar);
+                    spawn_bar(parent, "Shield", SHIELD_COLOR, ShieldBar);
+                    spawn_bar(parent, "Heat", HEAT_COLOR, WeaponHeatBar);
+                    parent.spawn((text_bundle(""), WeaponText));
+                });
+            parent
+                .spawn(column_bundle(AlignItems::End))
+                .with_children(|parent| {
+                    parent.spawn((text_bundle(&score_label(&score, &config)), ScoreText));
+                    parent.spawn((text_bundle(&lives_label(&lives)), LivesText));
+                    parent.spawn((text_bundle(&wave_label(&wave)), WaveText));
+                });
+        });
+}
+
+fn update_health_bar(
+    spaceship_query: Query<&Health, (With<Spaceship>, Changed<Health>)>,
+    mut bar_query: Query<&mut Style, With<HealthBar>>,
+) {
+    let Ok(health) = spaceship_query.get_single() else {
+        return;
+    };
+    for mut style in bar_query.iter_mut() {
+        style.width = bar_width(health.value / health.max);
+    }
+}
+
+fn update_shield_bar(
+    spaceship_query: Query<(Entity, Ref<ShieldGenerator>), With<Spaceship>>,
+    shield_query: Query<(), With<SpaceshipShield>>,
+    mut bar_query: Query<(&mut Style, &mut BackgroundColor), With<ShieldBar>>,
+    config: Res<GameConfig>,
+) {
+    let Ok((spaceship, shield_generator)) = spaceship_query.get_single() else {
+        return;
+    };
+    if !shield_generator.is_changed() {
+        return;
+    }
+    let is_shielded = shield_query.contains(spaceship);
+    for (mut style, mut background_color) in bar_query.iter_mut() {
+        style.width = bar_width(shield_generator.energy / config.spaceship.shield.max_energy);
+        // Brighter while the shield is up, dimmed while it can't be raised.
+        *background_color = if is_shielded {
+            SHIELD_ACTIVE_COLOR
+        } else if shield_generator.can_activate() {
+            SHIELD_COLOR
+        } else {
+            INACTIVE_BAR_COLOR
+        }
+        .into();
+    }
+}
+
+fn update_weapon_display(
+    spaceship_query: Query<&Weapon, (With<Spaceship>, Changed<Weapon>)>,
+    mut bar_query: Query<(&mut Style, &mut BackgroundColor), With<WeaponHeatBar>>,
+    mut text_query: Query<&mut Text, With<WeaponText>>,
+    config: Res<GameConfig>,
+) {
+    let Ok(weapon) = spaceship_query.get_single() else {
+        return;
+    };
+    for (mut style, mut background_color) in bar_query.iter_mut() {
+        style.width = bar_width(weapon.heat / config.weapon.max_heat);
+        *background_color = if weapon.overheated {
+            OVERHEATED_COLOR
+        } else {
+            HEAT_COLOR
+        }
+        .into();
+    }
+
+    let status = if weapon.overheated {
+        "overheated"
+    } else if weapon.is_ready() {
+        "ready"
+    } else {
+        "reloading"
+    };
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = format!("{} - {}", weapon_name(weapon.kind), status);
+    }
+}
+
+fn update_score_text(
+    mut query: Query<&mut Text, With<ScoreText>>,
+    score: Res<Score>,
+    config: Res<GameConfig>,
+) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = score_label(&score, &config);
+    }
+}
+
+fn update_lives_text(mut query: Query<&mut Text, With<LivesText>>, lives: Res<Lives>) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = lives_label(&lives);
+    }
+}
+
+fn update_wave_text(mut query: Query<&mut Text, With<WaveText>>, wave: Res<Wave>) {
+    for mut text in query.iter_mut() {
+        text.sections[0].value = wave_label(&wave);
+    }
+}
+
+fn score_label(score: &Score, config: &GameConfig) -> String {
+    let multiplier = score.multiplier(config.score.max_combo_multiplier);
+    if 1 < multiplier {
+        format!("Score: {}  x{}", score.value, multiplier)
+    } else {
+        format!("Score: {}", score.value)
+    }
+}
+
+fn lives_label(lives: &Lives) -> String {
+    format!("Lives: {}", lives.remaining)
+}
+
+fn wave_label(wave: &Wave) -> String {
+    format!("Wave {}", wave.number)
+}
+
+fn weapon_name(kind: WeaponKind) -> &'static str {
+    match kind {
+        WeaponKind::SingleShot => "Single shot",
+        WeaponKind::Spread => "Spread",
+        WeaponKind::Rapid => "Rapid",
+    }
+}
+
+fn bar_width(fraction: f32) -> Val {
+    Val::Percent(fraction.clamp(0.0, 1.0) * 100.0)
+}
+
+fn column_bundle(align_items: AlignItems) -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            align_items,
+            row_gap: Val::Px(ROW_GAP),
+            ..default()
+        },
+        ..default()
+    }
+}
+
+fn text_bundle(text: &str) -> TextBundle {
+    TextBundle::from_section(
+        text,
+        TextStyle {
+            font_size: FONT_SIZE,
+            color: TEXT_COLOR,
+            ..default()
+        },
+    )
+}
+
+/// Spawns a labelled bar whose fill carries `marker`, starting full.
+fn spawn_bar(parent: &mut ChildBuilder, label: &str, color: Color, marker: impl Component) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(ROW_GAP),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                style: Style {
+                    width: Val::Px(LABEL_WIDTH),
+                    ..default()
+                },
+                ..text_bundle(label)
+            });
+            parent
+                .spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(BAR_WIDTH),
+                        height: Val::Px(BAR_HEIGHT),
+                        ..default()
+                    },
+                    background_color: BAR_BACKGROUND_COLOR.into(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            background_color: color.into(),
+                            ..default()
+                        },
+                        marker,
+                    ));
+                });
+        });
+}
+
+#[derive(Component, Debug)]
+struct HealthBar;
+
+#[derive(Component, Debug)]
+struct ShieldBar;
+
+#[derive(Component, Debug)]
+struct WeaponHeatBar;
+
+#[derive(Component, Debug)]
+struct WeaponText;
+
+#[derive(Component, Debug)]
+struct ScoreText;
+
+#[derive(Component, Debug)]
+struct LivesText;
+
+#[derive(Component, Debug)]
+struct WaveText;
+
+const FONT_SIZE: f32 = 22.0;
+const HUD_PADDING: f32 = 16.0;
+const ROW_GAP: f32 = 8.0;
+const LABEL_WIDTH: f32 = 64.0;
+const BAR_WIDTH: f32 = 200.0;
+const BAR_HEIGHT: f32 = 12.0;
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BAR_BACKGROUND_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.15);
+const INACTIVE_BAR_COLOR: Color = Color::srgb(0.35, 0.35, 0.4);
+const HEALTH_COLOR: Color = Color::srgb(0.3, 0.85, 0.4);
+const SHIELD_COLOR: Color = Color::srgb(0.3, 0.6, 1.0);
+const SHIELD_ACTIVE_COLOR: Color = Color::srgb(0.6, 0.85, 1.0);
+const HEAT_COLOR: Color = Color::srgb(1.0, 0.65, 0.2);
+const OVERHEATED_COLOR: Color = Color::srgb(1.0, 0.25, 0.2);
@@ -0,0 +1,68 @@
+use bevy::prelude::{
+    App, Component, Fixed, FixedFirst, FixedLast, IntoSystemConfigs, Plugin, PostUpdate, Query, Res,
+    Time, Transform, TransformSystem,
+};
+
+/// Smooths rendering of entities simulated in `FixedUpdate`.
+///
+/// Each tick starts from the simulated transform and records where it ended; between ticks the
+/// rendered transform is blended between the last two simulated ones.
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedFirst, restore_simulated_transforms)
+            .add_systems(FixedLast, record_simulated_transforms)
+            .add_systems(
+                PostUpdate,
+                interpolate_transforms.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+fn restore_simulated_transforms(mut query: Query<(&mut Transform, &mut TransformInterpolation)>) {
+    for (mut transform, mut interpolation) in query.iter_mut() {
+        if let Some(end) = interpolation.end {
+            *transform = end;
+        }
+        interpolation.start = Some(*transform);
+    }
+}
+
+fn record_simulated_transforms(mut query: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in query.iter_mut() {
+        interpolation.start.get_or_insert(*transform);
+        interpolation.end = Some(*transform);
+    }
+}
+
+fn interpolate_transforms(
+    mut query: Query<(&mut Transform, &TransformInterpolation)>,
+    time: Res<Time<Fixed>>,
+) {
+    let fraction = time.overstep_fraction();
+    for (mut transform, interpolation) in query.iter_mut() {
+        let (Some(start), Some(end)) = (interpolation.start, interpolation.end) else {
+            continue;
+        };
+        *transform = Transform {
+            translation: start.translation.lerp(end.translation, fraction),
+            rotation: start.rotation.slerp(end.rotation, fraction),
+            scale: start.scale.lerp(end.scale, fraction),
+        };
+    }
+}
+
+/// Simulated transforms at the start and end of the last fixed tick.
+#[derive(Component, Debug, Default)]
+pub struct TransformInterpolation {
+    start: Option<Transform>,
+    end: Option<Transform>,
+}
+
+impl TransformInterpolation {
+    /// Shows the current tick's transform as is, instead of blending from where the entity was.
+    pub fn teleport(&mut self) {
+        self.start = None;
+    }
+}
@@ -0,0 +1,16 @@
+//! Collision layer bits for every entity kind, used to build [`CollisionLayers`].
+//!
+//! New kinds claim a bit here and join the filters of the kinds they interact with;
+//! `collision_detection` needs no changes.
+
+use crate::collision_detection::CollisionLayers;
+
+pub const SPACESHIP: u32 = 1 << 0;
+pub const SPACESHIP_MISSILE: u32 = 1 << 1;
+pub const ASTEROID: u32 = 1 << 2;
+
+pub const SPACESHIP_LAYERS: CollisionLayers = CollisionLayers::new(SPACESHIP, ASTEROID);
+pub const SPACESHIP_MISSILE_LAYERS: CollisionLayers =
+    CollisionLayers::new(SPACESHIP_MISSILE, ASTEROID);
+pub const ASTEROID_LAYERS: CollisionLayers =
+    CollisionLayers::new(ASTEROID, SPACESHIP | SPACESHIP_MISSILE);
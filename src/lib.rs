@@ -0,0 +1,27 @@
+pub mod action;
+pub mod arena;
+pub mod asset_loader;
+pub mod asteroid;
+pub mod camera;
+pub mod collider_shape;
+pub mod collision_detection;
+pub mod config;
+pub mod debug;
+pub mod despawn;
+pub mod effects;
+pub mod feedback;
+pub mod headless;
+pub mod health;
+pub mod health_bar;
+pub mod hud;
+pub mod interpolation;
+pub mod layers;
+pub mod menu;
+pub mod movement;
+pub mod rng;
+pub mod schedule;
+pub mod score;
+pub mod shield;
+pub mod spaceship;
+pub mod state;
+pub mod weapon;
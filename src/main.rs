@@ -1,28 +1,28 @@
 use bevy::DefaultPlugins;
 use bevy::prelude::{AmbientLight, App, ClearColor, Color};
 
-use movement::MovementPlugin;
-use spaceship::SpaceshipPlugin;
-
-use crate::asset_loader::AssetLoaderPlugin;
-use crate::asteroid::AsteroidPlugin;
-use crate::camera::CameraPlugin;
-use crate::collision_detection::CollisionDetectionPlugin;
-use crate::despawn::DespawnPlugin;
-use crate::schedule::SchedulePlugin;
-use crate::state::StatePlugin;
-
-mod asset_loader;
-mod asteroid;
-mod camera;
-mod collision_detection;
-mod debug;
-mod despawn;
-mod health;
-mod movement;
-mod schedule;
-mod spaceship;
-mod state;
+use bevy_spaceship::action::{ActionPlugin, BindingsFile};
+use bevy_spaceship::arena::ArenaPlugin;
+use bevy_spaceship::asset_loader::AssetLoaderPlugin;
+use bevy_spaceship::asteroid::AsteroidPlugin;
+use bevy_spaceship::camera::CameraPlugin;
+use bevy_spaceship::collision_detection::CollisionDetectionPlugin;
+use bevy_spaceship::config::ConfigPlugin;
+use bevy_spaceship::despawn::DespawnPlugin;
+use bevy_spaceship::effects::EffectsPlugin;
+use bevy_spaceship::feedback::FeedbackPlugin;
+use bevy_spaceship::health::HealthPlugin;
+use bevy_spaceship::health_bar::HealthBarPlugin;
+use bevy_spaceship::hud::HudPlugin;
+use bevy_spaceship::interpolation::InterpolationPlugin;
+use bevy_spaceship::menu::MenuPlugin;
+use bevy_spaceship::movement::MovementPlugin;
+use bevy_spaceship::rng::{RngPlugin, seed_from_args};
+use bevy_spaceship::schedule::SchedulePlugin;
+use bevy_spaceship::score::{HighScoreFile, ScorePlugin};
+use bevy_spaceship::spaceship::SpaceshipPlugin;
+use bevy_spaceship::state::StatePlugin;
+use bevy_spaceship::weapon::WeaponPlugin;
 
 fn main() {
     App::new()
@@ -31,19 +31,39 @@ fn main() {
             color: Color::default(),
             brightness: 750.0,
         })
+        .insert_resource(HighScoreFile(HIGH_SCORE_PATH.into()))
+        .insert_resource(BindingsFile(BINDINGS_PATH.into()))
         .add_plugins(DefaultPlugins)
         // core
+        .add_plugins(RngPlugin {
+            seed: seed_from_args(),
+        })
+        .add_plugins(ActionPlugin)
         .add_plugins(SchedulePlugin)
+        .add_plugins(InterpolationPlugin)
         .add_plugins(DespawnPlugin)
         .add_plugins(CameraPlugin)
         .add_plugins(AssetLoaderPlugin)
+        .add_plugins(ConfigPlugin)
         //.add_plugins(DebugPlugin)
         // game logic
         .add_plugins(StatePlugin)
+        .add_plugins(MenuPlugin)
+        .add_plugins(HudPlugin)
+        .add_plugins(HealthBarPlugin)
         .add_plugins(MovementPlugin)
+        .add_plugins(ArenaPlugin)
         .add_plugins(CollisionDetectionPlugin)
+        .add_plugins(HealthPlugin)
+        .add_plugins(ScorePlugin)
+        .add_plugins(EffectsPlugin)
+        .add_plugins(FeedbackPlugin)
         // components
         .add_plugins(SpaceshipPlugin)
         .add_plugins(AsteroidPlugin)
+        .add_plugins(WeaponPlugin)
         .run();
 }
+
+const HIGH_SCORE_PATH: &str = "high_scores.ron";
+const BINDINGS_PATH: &str = "bindings.ron";
@@ -0,0 +1,186 @@
+use bevy::app::AppExit;
+use bevy::prelude::{
+    AlignItems, App, BackgroundColor, BuildChildren, ButtonBundle, Changed, ChildBuilder, Color,
+    Commands, Component, Condition, default, EventWriter, FlexDirection, in_state, Interaction,
+    IntoSystemConfigs, JustifyContent, NextState, NodeBundle, OnEnter, Plugin, Query, Res, ResMut,
+    resource_changed, StateScoped, Style, Text, TextBundle, TextStyle, Update, Val, With,
+};
+
+use crate::asset_loader::LoadingProgress;
+use crate::score::{HighScores, Score};
+use crate::state::GameState;
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Loading), spawn_loading_screen)
+            .add_systems(
+                Update,
+                update_loading_screen.run_if(
+                    in_state(GameState::Loading).and_then(resource_changed::<LoadingProgress>),
+                ),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), spawn_main_menu)
+            .add_systems(OnEnter(GameState::GameOver), spawn_game_over_screen)
+            .add_systems(
+                Update,
+                menu_button_interactions
+                    .run_if(in_state(GameState::MainMenu).or_else(in_state(GameState::GameOver))),
+            );
+    }
+}
+
+fn spawn_loading_screen(mut commands: Commands) {
+    commands
+        .spawn((screen_bundle(), StateScoped(GameState::Loading)))
+        .with_children(|parent| {
+            parent.spawn((text_bundle("Loading...", TEXT_FONT_SIZE), LoadingText));
+        });
+}
+
+fn update_loading_screen(
+    mut query: Query<&mut Text, With<LoadingText>>,
+    progress: Res<LoadingProgress>,
+) {
+    let Ok(mut text) = query.get_single_mut() else {
+        return;
+    };
+
+    let section = &mut text.sections[0];
+    if let Some(error) = &progress.error {
+        section.value = error.clone();
+        section.style.color = ERROR_TEXT_COLOR;
+    } else {
+        section.value = format!("Loading... {}/{}", progress.loaded, progress.total);
+    }
+}
+
+fn spawn_main_menu(mut commands: Commands, high_scores: Res<HighScores>) {
+    commands
+        .spawn((screen_bundle(), StateScoped(GameState::MainMenu)))
+        .with_children(|parent| {
+            parent.spawn(text_bundle("Bevy Spaceship", TITLE_FONT_SIZE));
+            if let Some(&high_score) = high_scores.entries.first() {
+                parent.spawn(text_bundle(
+                    &format!("High score: {}", high_score),
+                    TEXT_FONT_SIZE,
+                ));
+            }
+            spawn_button(parent, "Play", MenuButton::Play);
+            spawn_button(parent, "Quit", MenuButton::Quit);
+        });
+}
+
+fn spawn_game_over_screen(mut commands: Commands, score: Res<Score>, high_scores: Res<HighScores>) {
+    let high_score = high_scores
+        .entries
+        .first()
+        .copied()
+        .unwrap_or_default()
+        .max(score.value);
+
+    commands
+        .spawn((screen_bundle(), StateScoped(GameState::GameOver)))
+        .with_children(|parent| {
+            parent.spawn(text_bundle("Game Over", TITLE_FONT_SIZE));
+            parent.spawn(text_bundle(
+                &format!("Final score: {}", score.value),
+                TEXT_FONT_SIZE,
+            ));
+            parent.spawn(text_bundle(
+                &format!("High score: {}", high_score),
+                TEXT_FONT_SIZE,
+            ));
+            spawn_button(parent, "Restart", MenuButton::Restart);
+            spawn_button(parent, "Quit", MenuButton::Quit);
+        });
+}
+
+fn menu_button_interactions(
+    mut query: Query<(&Interaction, &MenuButton, &mut BackgroundColor), Changed<Interaction>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit_writer: EventWriter<AppExit>,
+) {
+    for (interaction, button, mut background_color) in query.iter_mut() {
+        match interaction {
+            Interaction::Pressed => {
+                *background_color = BUTTON_PRESSED_COLOR.into();
+                match button {
+                    MenuButton::Play | MenuButton::Restart => next_state.set(GameState::InGame),
+                    MenuButton::Quit => {
+                        app_exit_writer.send(AppExit::Success);
+                    }
+                }
+            }
+            Interaction::Hovered => *background_color = BUTTON_HOVERED_COLOR.into(),
+            Interaction::None => *background_color = BUTTON_COLOR.into(),
+        }
+    }
+}
+
+fn screen_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(16.0),
+            ..default()
+        },
+        ..default()
+    }
+}
+
+fn text_bundle(text: &str, font_size: f32) -> TextBundle {
+    TextBundle::from_section(
+        text,
+        TextStyle {
+            font_size,
+            color: TEXT_COLOR,
+            ..default()
+        },
+    )
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: &str, button: MenuButton) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(240.0),
+                    height: Val::Px(56.0),
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                background_color: BUTTON_COLOR.into(),
+                ..default()
+            },
+            button,
+        ))
+        .with_children(|parent| {
+            parent.spawn(text_bundle(label, BUTTON_FONT_SIZE));
+        });
+}
+
+#[derive(Component, Debug)]
+struct LoadingText;
+
+#[derive(Component, Clone, Copy, Debug)]
+enum MenuButton {
+    Play,
+    Restart,
+    Quit,
+}
+
+const TITLE_FONT_SIZE: f32 = 64.0;
+const TEXT_FONT_SIZE: f32 = 28.0;
+const BUTTON_FONT_SIZE: f32 = 32.0;
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const ERROR_TEXT_COLOR: Color = Color::srgb(1.0, 0.35, 0.35);
+const BUTTON_COLOR: Color = Color::srgb(0.2, 0.15, 0.35);
+const BUTTON_HOVERED_COLOR: Color = Color::srgb(0.3, 0.22, 0.5);
+const BUTTON_PRESSED_COLOR: Color = Color::srgb(0.45, 0.35, 0.7);
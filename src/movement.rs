@@ -1,9 +1,10 @@
 use bevy::prelude::{
-    App, Bundle, Component, IntoSystemConfigs, Plugin, Query, Res, SceneBundle, Time, Transform,
-    Update, Vec3,
+    App, Bundle, Component, FixedUpdate, IntoSystemConfigs, Plugin, Query, Res, SceneBundle, Time,
+    Transform, Vec3,
 };
 
 use crate::collision_detection::Collider;
+use crate::interpolation::TransformInterpolation;
 use crate::schedule::InGameSet;
 
 pub struct MovementPlugin;
@@ -11,8 +12,13 @@ pub struct MovementPlugin;
 impl Plugin for MovementPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
-            Update,
-            (apply_acceleration, apply_velocity)
+            FixedUpdate,
+            (
+                apply_acceleration,
+                apply_drag,
+                apply_max_speed,
+                apply_velocity,
+            )
                 .chain()
                 .in_set(InGameSet::EntityUpdates),
         );
@@ -31,12 +37,25 @@ fn apply_acceleration(mut query: Query<(&mut Velocity, &Acceleration)>, time: Re
     }
 }
 
+fn apply_drag(mut query: Query<(&mut Velocity, &Drag)>, time: Res<Time>) {
+    for (mut velocity, drag) in query.iter_mut() {
+        velocity.value *= (-drag.value * time.delta_seconds()).exp();
+    }
+}
+
+fn apply_max_speed(mut query: Query<(&mut Velocity, &MaxSpeed)>) {
+    for (mut velocity, max_speed) in query.iter_mut() {
+        velocity.value = velocity.value.clamp_length_max(max_speed.value);
+    }
+}
+
 #[derive(Bundle)]
 pub struct MovingObjectBundle {
     pub velocity: Velocity,
     pub acceleration: Acceleration,
     pub collider: Collider,
     pub model: SceneBundle,
+    pub interpolation: TransformInterpolation,
 }
 
 #[derive(Component, Debug)]
@@ -60,3 +79,26 @@ impl Acceleration {
         Self { value }
     }
 }
+
+/// Exponential velocity damping; `0.0` keeps momentum forever.
+#[derive(Component, Debug)]
+pub struct Drag {
+    pub value: f32,
+}
+
+impl Drag {
+    pub fn new(value: f32) -> Self {
+        Self { value }
+    }
+}
+
+#[derive(Component, Debug)]
+pub struct MaxSpeed {
+    pub value: f32,
+}
+
+impl MaxSpeed {
+    pub fn new(value: f32) -> Self {
+        Self { value }
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::{
+    App, Condition, info, IntoSystemConfigs, Plugin, Res, ResMut, Resource, resource_changed,
+    resource_exists, Update,
+};
+use rand::{RngCore, SeedableRng};
+use rand::rngs::StdRng;
+
+use crate::config::GameConfig;
+
+/// Inserts [`GameRng`], seeded with `seed`, else with [`GameConfig::seed`], else randomly.
+#[derive(Default)]
+pub struct RngPlugin {
+    pub seed: Option<u64>,
+}
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = self.seed.unwrap_or_else(rand::random);
+        info!("Game seed: {}", seed);
+        app.insert_resource(GameRng::from_seed(seed));
+        // An explicit seed wins over the config.
+        if self.seed.is_none() {
+            app.add_systems(
+                Update,
+                apply_config_seed
+                    .run_if(resource_exists::<GameConfig>.and_then(resource_changed::<GameConfig>)),
+            );
+        }
+    }
+}
+
+fn apply_config_seed(config: Res<GameConfig>, mut rng: ResMut<GameRng>) {
+    let Some(seed) = config.seed else {
+        return;
+    };
+    // Reloading an unchanged config must not restart the sequence.
+    if rng.seed() != seed {
+        info!("Game seed from config: {}", seed);
+        *rng = GameRng::from_seed(seed);
+    }
+}
+
+/// Reads the seed from a `--seed <u64>` command line argument.
+pub fn seed_from_args() -> Option<u64> {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == SEED_ARGUMENT {
+            return args.next().and_then(|value| value.parse().ok());
+        }
+    }
+    None
+}
+
+/// Source of every random gameplay decision, so a seed reproduces a run.
+#[derive(Resource, Debug)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+const SEED_ARGUMENT: &str = "--seed";
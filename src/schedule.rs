@@ -1,6 +1,11 @@
-use bevy::prelude::{App, in_state, IntoSystemSetConfigs, Plugin, SystemSet, Update};
+use bevy::prelude::{
+    App, Fixed, FixedUpdate, in_state, IntoSystemConfigs, IntoSystemSetConfigs, Plugin, Res, ResMut,
+    resource_changed, SystemSet, Time, Update,
+};
+use bevy::transform::systems::{propagate_transforms, sync_simple_transforms};
 
-use crate::state::GameState;
+use crate::config::GameConfig;
+use crate::state::PauseState;
 
 #[derive(SystemSet, Hash, PartialEq, Eq, Clone, Debug)]
 pub enum InGameSet {
@@ -10,12 +15,13 @@ pub enum InGameSet {
     DespawnEntities,
 }
 
+/// Runs the [`InGameSet`] pipeline in `FixedUpdate`, at the tick rate from [`GameConfig`].
 pub struct SchedulePlugin;
 
 impl Plugin for SchedulePlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(
-            Update,
+            FixedUpdate,
             (
                 // There is a bug
                 // when InGameSet::CollisionDetection is placed after InGameSet::EntityUpdates,
@@ -26,7 +32,21 @@ impl Plugin for SchedulePlugin {
                 InGameSet::EntityUpdates,
             )
                 .chain()
-                .run_if(in_state(GameState::InGame)),
+                .run_if(in_state(PauseState::Running)),
+        )
+        .add_systems(
+            FixedUpdate,
+            // GlobalTransform is otherwise only propagated once per frame in PostUpdate,
+            // so ticks after the first would test collisions against stale positions.
+            (sync_simple_transforms, propagate_transforms).before(InGameSet::CollisionDetection),
+        )
+        .add_systems(
+            Update,
+            apply_tick_rate_config.run_if(resource_changed::<GameConfig>),
         );
     }
 }
+
+fn apply_tick_rate_config(config: Res<GameConfig>, mut time: ResMut<Time<Fixed>>) {
+    time.set_timestep_hz(config.simulation.tick_rate_hz as f64);
+}
@@ -0,0 +1,155 @@
+use std::fs;
+use std::path::PathBuf;
+
+use bevy::prelude::{
+    App, EventReader, FixedUpdate, info, IntoSystemConfigs, OnEnter, Plugin, Query, Res, ResMut,
+    Resource, resource_exists, Startup, Time, Timer, TimerMode, warn, With,
+};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::config::GameConfig;
+use crate::despawn::{despawn_dead_entities, EntityDied, EntityKind};
+use crate::schedule::InGameSet;
+use crate::spaceship::SpaceshipMissile;
+use crate::state::GameState;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .init_resource::<HighScores>()
+            .add_systems(
+                Startup,
+                load_high_scores.run_if(resource_exists::<HighScoreFile>),
+            )
+            .add_systems(FixedUpdate, tick_combo.in_set(InGameSet::EntityUpdates))
+            .add_systems(
+                FixedUpdate,
+                award_kills
+                    .after_ignore_deferred(despawn_dead_entities)
+                    .in_set(InGameSet::DespawnEntities),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                (
+                    record_high_score,
+                    save_high_scores.run_if(resource_exists::<HighScoreFile>),
+                )
+                    .chain(),
+            )
+            .add_systems(OnEnter(GameState::InGame), reset_score);
+    }
+}
+
+fn tick_combo(mut score: ResMut<Score>, time: Res<Time>) {
+    score.combo_timer.tick(time.delta());
+    if score.combo_timer.just_finished() {
+        score.combo = 0;
+    }
+}
+
+fn award_kills(
+    mut event_reader: EventReader<EntityDied>,
+    mut score: ResMut<Score>,
+    missile_query: Query<(), With<SpaceshipMissile>>,
+    config: Res<GameConfig>,
+) {
+    for event in event_reader.read() {
+        let EntityKind::Asteroid { size } = event.kind else {
+            continue;
+        };
+        if !event
+            .killer
+            .is_some_and(|killer| missile_query.contains(killer))
+        {
+            continue;
+        }
+
+        // Smaller fragments are harder to hit, so each tier below the spawned size adds a share.
+        let tier_bonus = config.asteroid.size.saturating_sub(size) + 1;
+        score.register_kill(config.score.asteroid_points * tier_bonus, &config);
+    }
+}
+
+fn record_high_score(score: Res<Score>, mut high_scores: ResMut<HighScores>) {
+    if high_scores.insert(score.value) {
+        info!("New high score entry: {}", score.value);
+    }
+}
+
+fn reset_score(mut score: ResMut<Score>) {
+    *score = Score::default();
+}
+
+fn load_high_scores(file: Res<HighScoreFile>, mut high_scores: ResMut<HighScores>) {
+    let Ok(contents) = fs::read_to_string(&file.0) else {
+        return;
+    };
+    match ron::from_str(&contents) {
+        Ok(loaded) => *high_scores = loaded,
+        Err(err) => warn!("Ignoring unreadable high scores {:?}: {}", file.0, err),
+    }
+}
+
+fn save_high_scores(file: Res<HighScoreFile>, high_scores: Res<HighScores>) {
+    let contents = match ron::ser::to_string_pretty(&*high_scores, PrettyConfig::default()) {
+        Ok(contents) => contents,
+        Err(err) => {
+            warn!("Could not serialize high scores: {}", err);
+            return;
+        }
+    };
+    if let Err(err) = fs::write(&file.0, contents) {
+        warn!("Could not save high scores to {:?}: {}", file.0, err);
+    }
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct Score {
+    pub value: u32,
+    /// Kills in the current combo; each kill within the combo window raises the multiplier.
+    pub combo: u32,
+    combo_timer: Timer,
+}
+
+impl Score {
+    pub fn multiplier(&self, max_multiplier: u32) -> u32 {
+        self.combo.clamp(1, max_multiplier.max(1))
+    }
+
+    fn register_kill(&mut self, points: u32, config: &GameConfig) {
+        self.combo += 1;
+        self.combo_timer = Timer::from_seconds(config.score.combo_window_seconds, TimerMode::Once);
+        self.value += points * self.multiplier(config.score.max_combo_multiplier);
+    }
+}
+
+/// Best final scores, highest first.
+#[derive(Resource, Serialize, Deserialize, Debug, Default)]
+pub struct HighScores {
+    pub entries: Vec<u32>,
+}
+
+impl HighScores {
+    /// Inserts `score` if it makes the table, returning whether it did.
+    pub fn insert(&mut self, score: u32) -> bool {
+        if score == 0 {
+            return false;
+        }
+        let position = self.entries.partition_point(|&entry| score <= entry);
+        if MAX_HIGH_SCORES <= position {
+            return false;
+        }
+        self.entries.insert(position, score);
+        self.entries.truncate(MAX_HIGH_SCORES);
+        true
+    }
+}
+
+/// Where [`HighScores`] are persisted between runs. Without it, high scores only live in memory.
+#[derive(Resource, Debug)]
+pub struct HighScoreFile(pub PathBuf);
+
+const MAX_HIGH_SCORES: usize = 10;
@@ -0,0 +1,59 @@
+//! The spaceship's shield, which [`apply_damage`](crate::health::apply_damage) drains before
+//! health. Raising, lowering and recharging it is up to `spaceship`.
+
+use std::time::Duration;
+
+use bevy::prelude::{Component, Timer, TimerMode};
+
+/// Active shield; present only while the shield is up.
+#[derive(Component, Debug)]
+pub struct SpaceshipShield {
+    pub duration: Timer,
+}
+
+impl SpaceshipShield {
+    pub fn new(duration_seconds: f32) -> Self {
+        Self {
+            duration: Timer::from_seconds(duration_seconds, TimerMode::Once),
+        }
+    }
+}
+
+/// Energy pool that powers [`SpaceshipShield`] and absorbs damage while it is up.
+#[derive(Component, Debug)]
+pub struct ShieldGenerator {
+    pub energy: f32,
+    cooldown: Timer,
+}
+
+impl ShieldGenerator {
+    pub fn new(energy: f32) -> Self {
+        Self {
+            energy,
+            cooldown: Timer::default(),
+        }
+    }
+
+    pub fn can_activate(&self) -> bool {
+        0.0 < self.energy && self.cooldown.remaining().is_zero()
+    }
+
+    /// Drains energy to absorb `damage` and returns the damage that got through.
+    pub fn absorb(&mut self, damage: f32) -> f32 {
+        let absorbed = damage.min(self.energy);
+        self.energy -= absorbed;
+        damage - absorbed
+    }
+
+    pub fn start_cooldown(&mut self, seconds: f32) {
+        self.cooldown = Timer::from_seconds(seconds, TimerMode::Once);
+    }
+
+    /// Ticks the cooldown and, once it is over, recharges energy up to `max_energy`.
+    pub fn recharge(&mut self, delta: Duration, rate: f32, max_energy: f32) {
+        self.cooldown.tick(delta);
+        if self.cooldown.remaining().is_zero() {
+            self.energy = (self.energy + rate * delta.as_secs_f32()).min(max_energy);
+        }
+    }
+}
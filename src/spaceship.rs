@@ -1,161 +1,341 @@
+use bevy::ecs::system::EntityCommands;
 use bevy::prelude::{
-    App, ButtonInput, Commands, Component, default, Entity, IntoSystemConfigs, KeyCode, NextState,
-    OnEnter, Plugin, PostStartup, Query, Res, ResMut, SceneBundle, StateScoped, Time, Transform,
-    Update, Vec3, With,
+    App, Bundle, Commands, Component, default, Entity, FixedUpdate, Has, IntoSystemConfigs,
+    NextState, OnEnter, Plugin, Query, Res, ResMut, Resource, resource_changed, SceneBundle,
+    StateScoped, Time, Transform, Vec3, Visibility, With,
 };
 
+use crate::action::{Action, ActionState};
+use crate::arena::BoundsPolicy;
 use crate::asset_loader::SceneAssets;
-use crate::collision_detection::{Collider, CollisionDamage};
-use crate::health::Health;
-use crate::movement::{Acceleration, MovingObjectBundle, Velocity};
+use crate::collision_detection::{Collider, CollisionDamage, FastMover, FitColliderToMesh};
+use crate::config::{FlightModel, GameConfig, SpaceshipConfig};
+use crate::health::{Armor, DamageKind, Health, Invulnerable};
+use crate::interpolation::TransformInterpolation;
+use crate::layers::{SPACESHIP_LAYERS, SPACESHIP_MISSILE_LAYERS};
+use crate::movement::{Acceleration, Drag, MaxSpeed, MovingObjectBundle, Velocity};
 use crate::schedule::InGameSet;
+use crate::shield::{ShieldGenerator, SpaceshipShield};
 use crate::state::GameState;
+use crate::weapon::{projectile_directions, Weapon, WeaponKind};
 
 pub struct SpaceshipPlugin;
 
 impl Plugin for SpaceshipPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PostStartup, spawn_spaceship)
-            .add_systems(OnEnter(GameState::GameOver), spawn_spaceship)
+        app.init_resource::<Lives>()
             .add_systems(
-                Update,
+                OnEnter(GameState::InGame),
+                (reset_lives, spawn_spaceship).chain(),
+            )
+            .add_systems(
+                FixedUpdate,
                 (
+                    apply_flight_model_config.run_if(resource_changed::<GameConfig>),
                     spaceship_movement_controls,
+                    spaceship_weapon_selection_controls,
                     spaceship_weapon_controls,
                     spaceship_shield_controls,
                 )
                     .chain()
                     .in_set(InGameSet::UserInput),
             )
-            .add_systems(Update, spaceship_destroyed.in_set(InGameSet::EntityUpdates));
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_spaceship_shield,
+                    update_invulnerability,
+                    spaceship_destroyed,
+                )
+                    .in_set(InGameSet::EntityUpdates),
+            );
     }
 }
 
-fn spawn_spaceship(mut commands: Commands, scene_assets: Res<SceneAssets>) {
-    commands.spawn((
+fn reset_lives(mut lives: ResMut<Lives>, config: Res<GameConfig>) {
+    lives.remaining = config.spaceship.lives;
+}
+
+fn spawn_spaceship(
+    mut commands: Commands,
+    scene_assets: Res<SceneAssets>,
+    config: Res<GameConfig>,
+) {
+    spawn_spaceship_entity(&mut commands, &scene_assets, &config);
+}
+
+fn spawn_spaceship_entity<'a>(
+    commands: &'a mut Commands,
+    scene_assets: &SceneAssets,
+    config: &GameConfig,
+) -> EntityCommands<'a> {
+    let mut spaceship = commands.spawn((
+        spaceship_bundle(scene_assets, &config.spaceship),
+        config.arena.spaceship,
+    ));
+    if config.spaceship.fit_collider_to_mesh {
+        spaceship.insert(FitColliderToMesh);
+    }
+    spaceship
+}
+
+fn spaceship_bundle(scene_assets: &SceneAssets, config: &SpaceshipConfig) -> impl Bundle {
+    (
         Spaceship,
+        SPACESHIP_LAYERS,
         StateScoped(GameState::InGame),
-        Health::new(SPACESHIP_HEALTH),
-        CollisionDamage::new(SPACESHIP_COLLISION_DAMAGE),
+        Health::new(config.health).with_regeneration(config.health_regeneration),
+        Armor::new(config.armor),
+        config.resistances.clone(),
+        CollisionDamage::new(config.collision_damage),
+        Weapon::new(WeaponKind::default()),
+        ShieldGenerator::new(config.shield.max_energy),
+        flight_model_bundle(config),
         MovingObjectBundle {
             velocity: Velocity::new(Vec3::ZERO),
             acceleration: Acceleration::new(Vec3::ZERO),
-            collider: Collider::new(3.0),
+            collider: Collider::from_shape(config.collider.clone()),
             model: SceneBundle {
                 scene: scene_assets.spaceship.clone(),
                 transform: Transform::from_translation(STARTING_TRANSLATION),
                 ..default()
             },
+            interpolation: TransformInterpolation::default(),
         },
-    ));
+    )
+}
+
+fn flight_model_bundle(config: &SpaceshipConfig) -> (Drag, MaxSpeed) {
+    match config.flight_model {
+        FlightModel::Arcade => (Drag::new(0.0), MaxSpeed::new(config.translation_speed)),
+        FlightModel::Newtonian => (
+            Drag::new(config.newtonian.drag),
+            MaxSpeed::new(config.newtonian.max_speed),
+        ),
+    }
+}
+
+fn apply_flight_model_config(
+    mut query: Query<(&mut Drag, &mut MaxSpeed), With<Spaceship>>,
+    config: Res<GameConfig>,
+) {
+    for (mut drag, mut max_speed) in query.iter_mut() {
+        (*drag, *max_speed) = flight_model_bundle(&config.spaceship);
+    }
 }
 
 fn spaceship_movement_controls(
-    mut query: Query<(&mut Transform, &mut Velocity), With<Spaceship>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(&mut Transform, &mut Velocity, &mut Acceleration), With<Spaceship>>,
+    action_state: Res<ActionState>,
     time: Res<Time>,
+    config: Res<GameConfig>,
 ) {
-    let Ok((mut transform, mut velocity)) = query.get_single_mut() else {
+    let Ok((mut transform, mut velocity, mut acceleration)) = query.get_single_mut() else {
         return;
     };
+    let config = &config.spaceship;
 
-    let mut movement = 0.0;
-    let mut rotation = 0.0;
-    let mut roll = 0.0;
-
-    if keyboard_input.pressed(KeyCode::KeyS) {
-        // not multiplied by delta seconds; already handled in the movement plugin.
-        movement = -SPACESHIP_TRANSLATION_SPEED;
-    } else if keyboard_input.pressed(KeyCode::KeyW) {
-        movement = SPACESHIP_TRANSLATION_SPEED;
-    }
-    if keyboard_input.pressed(KeyCode::KeyD) {
-        rotation = -SPACESHIP_ROTATION_SPEED * time.delta_seconds();
-    } else if keyboard_input.pressed(KeyCode::KeyA) {
-        rotation = SPACESHIP_ROTATION_SPEED * time.delta_seconds();
-    }
-    if keyboard_input.pressed(KeyCode::KeyQ) {
-        roll = -SPACESHIP_ROLL_SPEED * time.delta_seconds();
-    } else if keyboard_input.pressed(KeyCode::KeyE) {
-        roll = SPACESHIP_ROLL_SPEED * time.delta_seconds();
-    }
+    let thrust = action_state.value(Action::Thrust);
+    let forward = -transform.forward().as_vec3();
+    let rotation_speed = match config.flight_model {
+        FlightModel::Arcade => {
+            // not multiplied by delta seconds; already handled in the movement plugin.
+            velocity.value = forward * thrust * config.translation_speed;
+            acceleration.value = Vec3::ZERO;
+            config.rotation_speed
+        }
+        FlightModel::Newtonian => {
+            acceleration.value = forward * thrust * config.newtonian.thrust_acceleration;
+            config.newtonian.angular_velocity
+        }
+    };
+    let rotation = action_state.value(Action::Turn) * rotation_speed * time.delta_seconds();
+    let roll = action_state.value(Action::Roll) * config.roll_speed * time.delta_seconds();
 
-    velocity.value = -transform.forward() * movement;
     transform.rotate_y(rotation);
     transform.rotate_local_z(roll);
 }
 
+fn spaceship_weapon_selection_controls(
+    mut query: Query<&mut Weapon, With<Spaceship>>,
+    action_state: Res<ActionState>,
+) {
+    let Ok(mut weapon) = query.get_single_mut() else {
+        return;
+    };
+
+    if action_state.just_pressed(Action::SelectSingleShot) {
+        weapon.kind = WeaponKind::SingleShot;
+    } else if action_state.just_pressed(Action::SelectSpread) {
+        weapon.kind = WeaponKind::Spread;
+    } else if action_state.just_pressed(Action::SelectRapid) {
+        weapon.kind = WeaponKind::Rapid;
+    }
+}
+
 fn spaceship_weapon_controls(
     mut commands: Commands,
-    query: Query<&Transform, With<Spaceship>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(&Transform, &mut Weapon), With<Spaceship>>,
+    action_state: Res<ActionState>,
     scene_assets: Res<SceneAssets>,
+    config: Res<GameConfig>,
 ) {
-    if keyboard_input.pressed(KeyCode::Space) {
-        let Ok(spaceship_transform) = query.get_single() else {
-            return;
-        };
+    if !action_state.pressed(Action::Fire) {
+        return;
+    }
+    let Ok((spaceship_transform, mut weapon)) = query.get_single_mut() else {
+        return;
+    };
+    if !weapon.is_ready() {
+        return;
+    }
 
-        commands.spawn((
-            SpaceshipMissile,
-            StateScoped(GameState::InGame),
-            Health::new(MISSILE_HEALTH),
-            CollisionDamage::new(MISSILE_COLLISION_DAMAGE),
-            MovingObjectBundle {
-                velocity: Velocity::new(-spaceship_transform.forward() * MISSILE_SPEED),
-                acceleration: Acceleration::new(Vec3::ZERO),
-                collider: Collider::new(1.0),
-                model: SceneBundle {
-                    scene: scene_assets.missile.clone(),
-                    transform: Transform::from_translation(
-                        spaceship_transform.translation
-                            + -spaceship_transform.forward() * MISSILE_FORWARD_SPAWN_RANGE,
-                    ),
-                    ..default()
-                },
-            },
-        ));
+    let stats = config.weapon.stats(weapon.kind);
+    weapon.fire(stats, config.weapon.max_heat);
+    let forward = -spaceship_transform.forward().as_vec3();
+    for direction in projectile_directions(stats, forward) {
+        spawn_missile(
+            &mut commands,
+            &scene_assets,
+            &config.spaceship,
+            config.arena.missile,
+            spaceship_transform.translation
+                + forward * config.spaceship.missile_forward_spawn_range,
+            direction,
+        );
     }
 }
 
+fn spawn_missile(
+    commands: &mut Commands,
+    scene_assets: &SceneAssets,
+    config: &SpaceshipConfig,
+    bounds_policy: BoundsPolicy,
+    translation: Vec3,
+    direction: Vec3,
+) {
+    commands.spawn((
+        SpaceshipMissile,
+        bounds_policy,
+        SPACESHIP_MISSILE_LAYERS,
+        FastMover::default(),
+        StateScoped(GameState::InGame),
+        Health::new(config.missile_health),
+        CollisionDamage::new(config.missile_collision_damage).with_kind(DamageKind::Explosive),
+        MovingObjectBundle {
+            velocity: Velocity::new(direction * config.missile_speed),
+            acceleration: Acceleration::new(Vec3::ZERO),
+            collider: Collider::new(1.0),
+            model: SceneBundle {
+                scene: scene_assets.missile.clone(),
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+            interpolation: TransformInterpolation::default(),
+        },
+    ));
+}
+
 fn spaceship_shield_controls(
     mut commands: Commands,
-    query: Query<Entity, With<Spaceship>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(Entity, &mut ShieldGenerator, Has<SpaceshipShield>), With<Spaceship>>,
+    action_state: Res<ActionState>,
+    config: Res<GameConfig>,
 ) {
-    let Ok(spaceship) = query.get_single() else {
+    let Ok((spaceship, mut shield_generator, is_shielded)) = query.get_single_mut() else {
         return;
     };
-    if keyboard_input.pressed(KeyCode::Tab) {
-        commands.entity(spaceship).insert(SpaceshipShield);
+    let config = &config.spaceship.shield;
+
+    if action_state.pressed(Action::Shield) {
+        if !is_shielded && shield_generator.can_activate() {
+            commands
+                .entity(spaceship)
+                .insert(SpaceshipShield::new(config.duration_seconds));
+        }
+    } else if is_shielded {
+        commands.entity(spaceship).remove::<SpaceshipShield>();
+        shield_generator.start_cooldown(config.cooldown_seconds);
+    }
+}
+
+fn update_spaceship_shield(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ShieldGenerator, Option<&mut SpaceshipShield>)>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let config = &config.spaceship.shield;
+    for (entity, mut shield_generator, shield) in query.iter_mut() {
+        let Some(mut shield) = shield else {
+            shield_generator.recharge(time.delta(), config.recharge_rate, config.max_energy);
+            continue;
+        };
+
+        shield.duration.tick(time.delta());
+        if shield.duration.finished() || shield_generator.energy <= 0.0 {
+            commands.entity(entity).remove::<SpaceshipShield>();
+            shield_generator.start_cooldown(config.cooldown_seconds);
+        }
+    }
+}
+
+fn update_invulnerability(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Invulnerable, &mut Visibility), With<Spaceship>>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    for (entity, mut invulnerable, mut visibility) in query.iter_mut() {
+        invulnerable.timer.tick(time.delta());
+        if invulnerable.timer.finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+            *visibility = Visibility::Inherited;
+            continue;
+        }
+
+        let blink_phase =
+            invulnerable.timer.elapsed_secs() / config.spaceship.blink_interval_seconds;
+        *visibility = if blink_phase as u32 % 2 == 1 {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
     }
 }
 
 fn spaceship_destroyed(
+    mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
+    mut lives: ResMut<Lives>,
     query: Query<(), With<Spaceship>>,
+    scene_assets: Res<SceneAssets>,
+    config: Res<GameConfig>,
 ) {
-    if query.get_single().is_err() {
+    if query.get_single().is_ok() {
+        return;
+    }
+
+    lives.remaining = lives.remaining.saturating_sub(1);
+    if lives.remaining == 0 {
         next_state.set(GameState::GameOver);
+        return;
     }
+
+    spawn_spaceship_entity(&mut commands, &scene_assets, &config)
+        .insert(Invulnerable::new(config.spaceship.invulnerability_seconds));
 }
 
 const STARTING_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, -20.0);
-const SPACESHIP_TRANSLATION_SPEED: f32 = 25.0;
-const SPACESHIP_ROTATION_SPEED: f32 = 2.5;
-const SPACESHIP_ROLL_SPEED: f32 = 2.5;
-const MISSILE_SPEED: f32 = 50.0;
-const MISSILE_FORWARD_SPAWN_RANGE: f32 = 10.0;
-const SPACESHIP_HEALTH: f32 = 100.0;
-const SPACESHIP_COLLISION_DAMAGE: f32 = 100.0;
-const MISSILE_HEALTH: f32 = 1.0;
-const MISSILE_COLLISION_DAMAGE: f32 = 10.0;
 
 #[derive(Component, Debug)]
 pub struct Spaceship;
 
-#[derive(Component, Debug)]
-pub struct SpaceshipMissile;
+/// Ships left in the current run, including the one in play.
+#[derive(Resource, Debug, Default)]
+pub struct Lives {
+    pub remaining: u32,
+}
 
 #[derive(Component, Debug)]
-pub struct SpaceshipShield;
+pub struct SpaceshipMissile;
@@ -1,50 +1,53 @@
 use bevy::app::App;
-use bevy::input::ButtonInput;
 use bevy::prelude::{
-    AppExtStates, in_state, IntoSystemConfigs, KeyCode, NextState, Plugin, Res, ResMut, State,
-    States, Update,
+    AppExtStates, FixedUpdate, in_state, IntoSystemConfigs, NextState, Plugin, Res, ResMut, State,
+    States, StateSet, SubStates,
 };
 
+use crate::action::{Action, ActionState};
+
 #[derive(States, Clone, Copy, Eq, PartialEq, Hash, Default, Debug)]
 pub enum GameState {
     #[default]
+    Loading,
+    MainMenu,
     InGame,
-    Paused,
     GameOver,
 }
 
+/// Whether a run is paused. Only exists while in [`GameState::InGame`], so pausing keeps the
+/// entities scoped to the run alive.
+#[derive(SubStates, Clone, Copy, Eq, PartialEq, Hash, Default, Debug)]
+#[source(GameState = GameState::InGame)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
 pub struct StatePlugin;
 
 impl Plugin for StatePlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .add_sub_state::<PauseState>()
             .enable_state_scoped_entities::<GameState>()
             .add_systems(
-                Update,
-                (
-                    game_state_input_events,
-                    transition_to_in_game.run_if(in_state(GameState::GameOver)),
-                ),
+                FixedUpdate,
+                game_state_input_events.run_if(in_state(GameState::InGame)),
             );
     }
 }
 
 pub fn game_state_input_events(
-    mut next_state: ResMut<NextState<GameState>>,
-    current_state: Res<State<GameState>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+    current_state: Res<State<PauseState>>,
+    action_state: Res<ActionState>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyP) {
+    if action_state.just_pressed(Action::Pause) {
         match current_state.get() {
-            GameState::InGame => {
-                next_state.set(GameState::Paused);
-            }
-            GameState::Paused => next_state.set(GameState::InGame),
-            _ => {}
+            PauseState::Running => next_state.set(PauseState::Paused),
+            PauseState::Paused => next_state.set(PauseState::Running),
         }
     }
 }
-
-pub fn transition_to_in_game(mut next_state: ResMut<NextState<GameState>>) {
-    next_state.set(GameState::InGame);
-}
@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use bevy::prelude::{
+    App, Component, FixedUpdate, IntoSystemConfigs, Plugin, Quat, Query, Res, Time, Timer,
+    TimerMode, Vec3,
+};
+
+use crate::config::{GameConfig, WeaponStats};
+use crate::schedule::InGameSet;
+
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedUpdate, cool_weapons.in_set(InGameSet::EntityUpdates));
+    }
+}
+
+fn cool_weapons(mut query: Query<&mut Weapon>, time: Res<Time>, config: Res<GameConfig>) {
+    for mut weapon in query.iter_mut() {
+        weapon.cool(time.delta(), config.weapon.heat_dissipation);
+    }
+}
+
+/// Directions of the projectiles fired in one shot, fanned out evenly around `forward`.
+pub fn projectile_directions(stats: &WeaponStats, forward: Vec3) -> impl Iterator<Item = Vec3> {
+    let count = stats.projectile_count.max(1);
+    let spread = stats.spread_degrees.to_radians();
+    (0..count).map(move |index| {
+        if count == 1 {
+            return forward;
+        }
+        let angle = -spread / 2.0 + spread * index as f32 / (count - 1) as f32;
+        Quat::from_rotation_y(angle) * forward
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum WeaponKind {
+    #[default]
+    SingleShot,
+    Spread,
+    Rapid,
+}
+
+#[derive(Component, Debug)]
+pub struct Weapon {
+    pub kind: WeaponKind,
+    pub heat: f32,
+    pub overheated: bool,
+    cooldown: Timer,
+}
+
+impl Weapon {
+    pub fn new(kind: WeaponKind) -> Self {
+        Self {
+            kind,
+            heat: 0.0,
+            overheated: false,
+            cooldown: Timer::default(),
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        !self.overheated && self.cooldown.remaining().is_zero()
+    }
+
+    /// Starts the cooldown of the current weapon kind and builds up heat.
+    pub fn fire(&mut self, stats: &WeaponStats, max_heat: f32) {
+        self.cooldown = Timer::from_seconds(stats.cooldown_seconds, TimerMode::Once);
+        self.heat += stats.heat_per_shot;
+        if max_heat <= self.heat {
+            self.overheated = true;
+        }
+    }
+
+    /// Ticks the cooldown and dissipates heat. An overheated weapon stays locked until fully cool.
+    pub fn cool(&mut self, delta: Duration, heat_dissipation: f32) {
+        self.cooldown.tick(delta);
+        self.heat = (self.heat - heat_dissipation * delta.as_secs_f32()).max(0.0);
+        if self.heat == 0.0 {
+            self.overheated = false;
+        }
+    }
+}
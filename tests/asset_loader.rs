@@ -0,0 +1,96 @@
+use std::time::{Duration, Instant};
+
+use bevy::asset::{Asset, AssetLoader, AssetPlugin, LoadContext};
+use bevy::asset::io::Reader;
+use bevy::prelude::{App, AppExtStates, AssetApp, MinimalPlugins, Scene, State, TypePath};
+use bevy::state::app::StatesPlugin;
+
+use bevy_spaceship::asset_loader::{AssetLoaderPlugin, LoadingProgress};
+use bevy_spaceship::state::GameState;
+
+#[derive(Asset, TypePath)]
+struct GlbFile;
+
+/// Stands in for the glTF loader and rejects every file, like a corrupt `.glb` would.
+struct CorruptGlbLoader;
+
+impl AssetLoader for CorruptGlbLoader {
+    type Asset = GlbFile;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load<'a>(
+        &'a self,
+        _reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<GlbFile, std::io::Error> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "not a glTF file",
+        ))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["glb"]
+    }
+}
+
+fn loading_app(file_path: String) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path,
+            watch_for_changes_override: Some(false),
+            ..Default::default()
+        },
+        StatesPlugin,
+    ))
+    .init_asset::<Scene>()
+    .init_asset::<GlbFile>()
+    .register_asset_loader(CorruptGlbLoader)
+    .init_state::<GameState>()
+    .add_plugins(AssetLoaderPlugin);
+    app
+}
+
+fn update_until_error(app: &mut App) -> Option<String> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while app.world().resource::<LoadingProgress>().error.is_none() && Instant::now() < deadline {
+        app.update();
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    app.world().resource::<LoadingProgress>().error.clone()
+}
+
+#[test]
+fn corrupt_scene_file_reports_a_loading_error() {
+    let dir = std::env::temp_dir().join(format!("bevy-spaceship-corrupt-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for file in ["Planet.glb", "Spaceship.glb", "Missiles.glb"] {
+        std::fs::write(dir.join(file), b"garbage").unwrap();
+    }
+
+    let mut app = loading_app(dir.to_string_lossy().into_owned());
+    let error = update_until_error(&mut app);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(error.is_some_and(|error| error.contains("not a glTF file")));
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::Loading
+    );
+}
+
+#[test]
+fn missing_scene_file_reports_a_loading_error() {
+    let mut app = loading_app("tests/missing_assets".to_string());
+    let error = update_until_error(&mut app);
+
+    assert!(error.is_some_and(|error| error.contains(".glb")));
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::Loading
+    );
+}
@@ -0,0 +1,135 @@
+use std::f32::consts::FRAC_PI_4;
+
+use bevy::prelude::{Quat, Vec3};
+
+use bevy_spaceship::collider_shape::{ColliderShape, WorldShape};
+
+fn sphere(x: f32, radius: f32) -> WorldShape {
+    ColliderShape::Sphere { radius }.to_world(Vec3::new(x, 0.0, 0.0), Quat::IDENTITY)
+}
+
+/// Capsule lying along the Z axis.
+fn capsule(x: f32, half_length: f32, radius: f32) -> WorldShape {
+    ColliderShape::Capsule {
+        start: Vec3::new(0.0, 0.0, -half_length),
+        end: Vec3::new(0.0, 0.0, half_length),
+        radius,
+    }
+    .to_world(Vec3::new(x, 0.0, 0.0), Quat::IDENTITY)
+}
+
+fn cuboid(x: f32, half_extents: Vec3, rotation: Quat) -> WorldShape {
+    ColliderShape::Cuboid {
+        center: Vec3::ZERO,
+        half_extents,
+    }
+    .to_world(Vec3::new(x, 0.0, 0.0), rotation)
+}
+
+fn compound(x: f32) -> WorldShape {
+    ColliderShape::Compound {
+        spheres: vec![
+            (Vec3::new(-2.0, 0.0, 0.0), 1.0),
+            (Vec3::new(2.0, 0.0, 0.0), 1.0),
+        ],
+    }
+    .to_world(Vec3::new(x, 0.0, 0.0), Quat::IDENTITY)
+}
+
+/// Checks both argument orders, since the narrowphase must be symmetric.
+fn assert_intersects(a: &WorldShape, b: &WorldShape, expected: bool) {
+    assert_eq!(a.intersects(b), expected, "{:?} vs {:?}", a, b);
+    assert_eq!(b.intersects(a), expected, "{:?} vs {:?}", b, a);
+}
+
+#[test]
+fn sphere_pairs() {
+    assert_intersects(&sphere(0.0, 1.0), &sphere(1.9, 1.0), true);
+    assert_intersects(&sphere(0.0, 1.0), &sphere(2.1, 1.0), false);
+}
+
+#[test]
+fn sphere_capsule_pairs() {
+    // Beside the middle of the capsule, where a bounding sphere would be much larger.
+    assert_intersects(&sphere(0.0, 1.0), &capsule(1.9, 3.0, 1.0), true);
+    assert_intersects(&sphere(0.0, 1.0), &capsule(2.1, 3.0, 1.0), false);
+}
+
+#[test]
+fn sphere_cuboid_pairs() {
+    assert_intersects(
+        &sphere(0.0, 1.0),
+        &cuboid(1.9, Vec3::ONE, Quat::IDENTITY),
+        true,
+    );
+    assert_intersects(
+        &sphere(0.0, 1.0),
+        &cuboid(2.1, Vec3::ONE, Quat::IDENTITY),
+        false,
+    );
+    // Turned 45 degrees, the box reaches sqrt(2) towards the sphere.
+    assert_intersects(
+        &sphere(0.0, 1.0),
+        &cuboid(2.3, Vec3::ONE, Quat::from_rotation_y(FRAC_PI_4)),
+        true,
+    );
+}
+
+#[test]
+fn capsule_pairs() {
+    assert_intersects(&capsule(0.0, 3.0, 1.0), &capsule(1.9, 3.0, 1.0), true);
+    assert_intersects(&capsule(0.0, 3.0, 1.0), &capsule(2.1, 3.0, 1.0), false);
+}
+
+#[test]
+fn capsule_cuboid_pairs() {
+    assert_intersects(
+        &capsule(0.0, 3.0, 1.0),
+        &cuboid(1.9, Vec3::ONE, Quat::IDENTITY),
+        true,
+    );
+    assert_intersects(
+        &capsule(0.0, 3.0, 1.0),
+        &cuboid(2.1, Vec3::ONE, Quat::IDENTITY),
+        false,
+    );
+}
+
+#[test]
+fn cuboid_pairs() {
+    assert_intersects(
+        &cuboid(0.0, Vec3::ONE, Quat::IDENTITY),
+        &cuboid(1.9, Vec3::ONE, Quat::IDENTITY),
+        true,
+    );
+    assert_intersects(
+        &cuboid(0.0, Vec3::ONE, Quat::IDENTITY),
+        &cuboid(2.1, Vec3::ONE, Quat::IDENTITY),
+        false,
+    );
+    assert_intersects(
+        &cuboid(0.0, Vec3::ONE, Quat::IDENTITY),
+        &cuboid(2.3, Vec3::ONE, Quat::from_rotation_y(FRAC_PI_4)),
+        true,
+    );
+    assert_intersects(
+        &cuboid(0.0, Vec3::ONE, Quat::IDENTITY),
+        &cuboid(2.5, Vec3::ONE, Quat::from_rotation_y(FRAC_PI_4)),
+        false,
+    );
+}
+
+#[test]
+fn compound_pairs() {
+    // The gap between the two spheres of the compound is not part of it.
+    assert_intersects(&compound(0.0), &sphere(0.0, 0.5), false);
+    assert_intersects(&compound(0.0), &sphere(3.9, 1.0), true);
+    assert_intersects(&compound(0.0), &capsule(3.9, 3.0, 1.0), true);
+    assert_intersects(
+        &compound(0.0),
+        &cuboid(4.1, Vec3::ONE, Quat::IDENTITY),
+        false,
+    );
+    assert_intersects(&compound(0.0), &compound(5.9), true);
+    assert_intersects(&compound(0.0), &compound(6.1), false);
+}
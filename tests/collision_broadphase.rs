@@ -0,0 +1,86 @@
+use std::time::Instant;
+
+use bevy::prelude::{Entity, Vec3};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use bevy_spaceship::collision_detection::CollisionGrid;
+
+const ENTITY_COUNT: u32 = 6_000;
+const FIELD_HALF_SIZE: f32 = 250.0;
+
+fn random_colliders() -> Vec<(Entity, Vec3, f32)> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..ENTITY_COUNT)
+        .map(|index| {
+            let translation = Vec3::new(
+                rng.gen_range(-FIELD_HALF_SIZE..FIELD_HALF_SIZE),
+                0.0,
+                rng.gen_range(-FIELD_HALF_SIZE..FIELD_HALF_SIZE),
+            );
+            let radius = if rng.gen_bool(0.1) { 3.0 } else { 1.0 };
+            (Entity::from_raw(index), translation, radius)
+        })
+        .collect()
+}
+
+fn brute_force_pairs(colliders: &[(Entity, Vec3, f32)]) -> Vec<(Entity, Entity)> {
+    let mut pairs = vec![];
+    for &(entity_a, translation_a, radius_a) in colliders.iter() {
+        for &(entity_b, translation_b, radius_b) in colliders.iter() {
+            if entity_a != entity_b && translation_a.distance(translation_b) < radius_a + radius_b {
+                pairs.push((entity_a, entity_b));
+            }
+        }
+    }
+    pairs
+}
+
+fn grid(colliders: &[(Entity, Vec3, f32)]) -> CollisionGrid {
+    let mut grid = CollisionGrid::new(8.0);
+    for &(entity, translation, radius) in colliders.iter() {
+        grid.insert(entity, translation, radius);
+    }
+    grid
+}
+
+fn grid_pairs(colliders: &[(Entity, Vec3, f32)]) -> Vec<(Entity, Entity)> {
+    let grid = grid(colliders);
+    let mut pairs = vec![];
+    grid.for_each_collision(|entity_a, entity_b| pairs.push((entity_a, entity_b)));
+    pairs
+}
+
+#[test]
+fn grid_broadphase_matches_brute_force() {
+    let colliders = random_colliders();
+    let grid_pairs = grid_pairs(&colliders);
+    assert!(!grid_pairs.is_empty());
+    assert_eq!(grid_pairs, brute_force_pairs(&colliders));
+}
+
+#[test]
+fn grid_broadphase_tests_a_small_fraction_of_all_pairs() {
+    let colliders = random_colliders();
+    let all_pairs = colliders.len() * (colliders.len() - 1);
+    // Spread-out colliders only share cells with their few neighbours, so the narrowphase work
+    // grows roughly linearly instead of quadratically.
+    assert!(grid(&colliders).candidate_pair_count() < all_pairs / 100);
+}
+
+/// Wall-clock comparison, too noisy for shared CI machines. Run with `cargo test -- --ignored`.
+#[test]
+#[ignore]
+fn grid_broadphase_is_faster_than_brute_force() {
+    let colliders = random_colliders();
+
+    let brute_force_start = Instant::now();
+    brute_force_pairs(&colliders);
+    let brute_force_elapsed = brute_force_start.elapsed();
+
+    let grid_start = Instant::now();
+    grid_pairs(&colliders);
+    let grid_elapsed = grid_start.elapsed();
+
+    assert!(grid_elapsed < brute_force_elapsed);
+}
@@ -0,0 +1,39 @@
+use bevy_spaceship::config::{GameConfig, GameConfigError};
+
+#[test]
+fn shipped_config_is_valid() {
+    let config: GameConfig = ron::from_str(include_str!("../assets/game.config.ron")).unwrap();
+
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn non_positive_spawn_time_is_rejected() {
+    let mut config = GameConfig::default();
+    config.asteroid.spawn_time_seconds = 0.0;
+
+    assert!(matches!(
+        config.validate(),
+        Err(GameConfigError::NotPositive {
+            field: "asteroid.spawn_time_seconds",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn asteroid_sizes_out_of_order_are_rejected() {
+    let mut config = GameConfig::default();
+    config.asteroid.min_size = 0;
+    assert!(matches!(
+        config.validate(),
+        Err(GameConfigError::InvalidSizes { .. })
+    ));
+
+    config.asteroid.min_size = 2;
+    config.asteroid.size = 1;
+    assert!(matches!(
+        config.validate(),
+        Err(GameConfigError::InvalidSizes { .. })
+    ));
+}
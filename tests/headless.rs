@@ -0,0 +1,1012 @@
+use std::time::Duration;
+
+use bevy::app::{PluginGroup, Plugins};
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::{
+    App, ButtonInput, Camera, Children, default, Entity, Events, KeyCode, NextState, Parent,
+    SceneBundle, State, Style, Text, Time, Transform, TransformBundle, Val, Vec3, Virtual, With,
+};
+use bevy::time::TimeUpdateStrategy;
+
+use bevy_spaceship::action::{Action, Binding, BindingsFile, InputBindings, InputSource};
+use bevy_spaceship::arena::{ArenaBounds, BoundsPolicy};
+use bevy_spaceship::asteroid::{Asteroid, Wave};
+use bevy_spaceship::camera::{CameraController, CameraMode, CameraPlugin, CameraShake};
+use bevy_spaceship::collision_detection::{
+    Collider, CollisionDamage, CollisionEvent, CollisionLayers, CollisionPhase, FastMover,
+};
+use bevy_spaceship::config::{FlightModel, GameConfig};
+use bevy_spaceship::despawn::{EntityDied, EntityKind};
+use bevy_spaceship::feedback::FeedbackPlugin;
+use bevy_spaceship::headless::HeadlessGamePlugin;
+use bevy_spaceship::health::{
+    Armor, DamageEvent, DamageKind, EntityDestroyed, Health, Invulnerable, Resistances,
+};
+use bevy_spaceship::health_bar::{HealthBarPlugin, WorldHealthBar};
+use bevy_spaceship::hud::HudPlugin;
+use bevy_spaceship::interpolation::TransformInterpolation;
+use bevy_spaceship::layers::{ASTEROID, ASTEROID_LAYERS, SPACESHIP, SPACESHIP_MISSILE_LAYERS};
+use bevy_spaceship::movement::{Acceleration, MovingObjectBundle, Velocity};
+use bevy_spaceship::rng::{GameRng, RngPlugin};
+use bevy_spaceship::score::Score;
+use bevy_spaceship::shield::{ShieldGenerator, SpaceshipShield};
+use bevy_spaceship::spaceship::{Lives, Spaceship, SpaceshipMissile};
+use bevy_spaceship::state::{GameState, PauseState};
+
+const SEED: u64 = 7;
+const FRAME_MILLIS: u64 = 100;
+
+fn headless_app() -> App {
+    seeded_headless_app(SEED)
+}
+
+fn seeded_headless_app(seed: u64) -> App {
+    headless_app_with_frame_time(seed, FRAME_MILLIS)
+}
+
+fn headless_app_with_frame_time(seed: u64, frame_millis: u64) -> App {
+    let mut app = App::new();
+    app.add_plugins(HeadlessGamePlugin.set(RngPlugin { seed: Some(seed) }))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            frame_millis,
+        )));
+    app.update();
+    app
+}
+
+fn spaceship(app: &mut App) -> Option<Entity> {
+    app.world_mut()
+        .query_filtered::<Entity, With<Spaceship>>()
+        .iter(app.world())
+        .next()
+}
+
+fn asteroid_timeline(app: &mut App, frames: usize) -> Vec<(Entity, Vec3)> {
+    let mut timeline = vec![];
+    for _ in 0..frames {
+        app.update();
+        timeline.extend(
+            app.world_mut()
+                .query_filtered::<(Entity, &Transform), With<Asteroid>>()
+                .iter(app.world())
+                .map(|(entity, transform)| (entity, transform.translation)),
+        );
+    }
+    timeline
+}
+
+fn game_state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+#[test]
+fn spaceship_spawns_on_startup() {
+    let mut app = headless_app();
+
+    assert!(spaceship(&mut app).is_some());
+    assert_eq!(game_state(&app), GameState::InGame);
+}
+
+#[test]
+fn asteroids_spawn_over_time() {
+    let mut app = headless_app();
+
+    for _ in 0..15 {
+        app.update();
+    }
+
+    let asteroid_count = app
+        .world_mut()
+        .query_filtered::<(), With<Asteroid>>()
+        .iter(app.world())
+        .count();
+    assert!(asteroid_count > 0);
+}
+
+fn spawn_asteroid_at(app: &mut App, translation: Vec3) -> Entity {
+    let asteroid = spawn_collider_at(app, translation, Vec3::ZERO);
+    app.world_mut().entity_mut(asteroid).insert((
+        Asteroid::new(1),
+        ASTEROID_LAYERS,
+        Health::new(50.0),
+        CollisionDamage::new(35.0),
+    ));
+    asteroid
+}
+
+#[test]
+fn collision_damages_spaceship() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+    let translation = app.world().get::<Transform>(spaceship).unwrap().translation;
+
+    spawn_asteroid_at(&mut app, translation);
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(spaceship).unwrap().value, 65.0);
+}
+
+#[test]
+fn collision_layers_decide_which_pairs_interact() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+    let translation = app.world().get::<Transform>(spaceship).unwrap().translation;
+
+    // A new kind only needs a layer bit, which the spaceship has to accept as well.
+    const ENEMY_BULLET: u32 = 1 << 8;
+    app.world_mut()
+        .entity_mut(spaceship)
+        .insert(CollisionLayers::new(SPACESHIP, ASTEROID | ENEMY_BULLET));
+    let enemy_bullet = spawn_collider_at(&mut app, translation, Vec3::ZERO);
+    app.world_mut().entity_mut(enemy_bullet).insert((
+        CollisionLayers::new(ENEMY_BULLET, SPACESHIP),
+        Health::new(1.0),
+        CollisionDamage::new(5.0),
+    ));
+    let first_asteroid = spawn_asteroid_at(&mut app, Vec3::new(40.0, 0.0, 40.0));
+    let second_asteroid = spawn_asteroid_at(&mut app, Vec3::new(40.0, 0.0, 40.0));
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(spaceship).unwrap().value, 95.0);
+    assert_eq!(
+        app.world().get::<Health>(first_asteroid).unwrap().value,
+        50.0
+    );
+    assert_eq!(
+        app.world().get::<Health>(second_asteroid).unwrap().value,
+        50.0
+    );
+}
+
+fn spawn_collider_at(app: &mut App, translation: Vec3, velocity: Vec3) -> Entity {
+    app.world_mut()
+        .spawn(MovingObjectBundle {
+            velocity: Velocity::new(velocity),
+            acceleration: Acceleration::new(Vec3::ZERO),
+            collider: Collider::new(1.0),
+            model: SceneBundle {
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+            interpolation: TransformInterpolation::default(),
+        })
+        .id()
+}
+
+fn spawn_missile_at(app: &mut App, translation: Vec3, velocity: Vec3) -> Entity {
+    let missile = spawn_collider_at(app, translation, velocity);
+    app.world_mut().entity_mut(missile).insert((
+        SpaceshipMissile,
+        SPACESHIP_MISSILE_LAYERS,
+        Health::new(1.0),
+        CollisionDamage::new(10.0),
+    ));
+    missile
+}
+
+#[test]
+fn simultaneous_missile_hits_all_damage_asteroid() {
+    let mut app = headless_app();
+    let translation = Vec3::new(40.0, 0.0, 40.0);
+    let asteroid = spawn_asteroid_at(&mut app, translation);
+    for _ in 0..3 {
+        spawn_missile_at(&mut app, translation, Vec3::ZERO);
+    }
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(asteroid).unwrap().value, 20.0);
+}
+
+#[test]
+fn contact_reports_start_stay_and_end_once_per_pair() {
+    let mut app = headless_app();
+    let translation = Vec3::new(40.0, 0.0, 40.0);
+    let resting = spawn_collider_at(&mut app, translation, Vec3::ZERO);
+    // Leaves contact after a handful of ticks.
+    let leaving = spawn_collider_at(&mut app, translation, Vec3::new(60.0, 0.0, 0.0));
+    let pair = (resting.min(leaving), resting.max(leaving));
+
+    let mut reader = app
+        .world()
+        .resource::<Events<CollisionEvent>>()
+        .get_reader();
+    let mut phases = vec![];
+    for _ in 0..3 {
+        app.update();
+        let events = app.world().resource::<Events<CollisionEvent>>();
+        phases.extend(
+            reader
+                .read(events)
+                .filter(|event| (event.entity_a, event.entity_b) == pair)
+                .map(|event| event.phase),
+        );
+    }
+
+    assert_eq!(phases.first(), Some(&CollisionPhase::Started));
+    assert_eq!(phases.last(), Some(&CollisionPhase::Ended));
+    assert!(phases[1..phases.len() - 1]
+        .iter()
+        .all(|&phase| phase == CollisionPhase::Stay));
+    assert!(2 < phases.len());
+}
+
+#[test]
+fn overlapping_pair_keeps_dealing_collision_damage() {
+    let mut app = headless_app();
+    let translation = Vec3::new(40.0, 0.0, 40.0);
+    let target = spawn_collider_at(&mut app, translation, Vec3::ZERO);
+    app.world_mut()
+        .entity_mut(target)
+        .insert(Health::new(100.0));
+    let source = spawn_collider_at(&mut app, translation, Vec3::ZERO);
+    app.world_mut()
+        .entity_mut(source)
+        .insert(CollisionDamage::new(1.0));
+
+    app.update();
+    let after_first_frame = app.world().get::<Health>(target).unwrap().value;
+    app.update();
+
+    assert!(after_first_frame < 99.0);
+    assert!(app.world().get::<Health>(target).unwrap().value < after_first_frame);
+}
+
+#[test]
+fn shield_absorbs_collision_damage() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+    let translation = app.world().get::<Transform>(spaceship).unwrap().translation;
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Tab);
+    app.update();
+    assert!(app.world().get::<SpaceshipShield>(spaceship).is_some());
+
+    spawn_asteroid_at(&mut app, translation);
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(spaceship).unwrap().value, 100.0);
+    assert_eq!(
+        app.world()
+            .get::<ShieldGenerator>(spaceship)
+            .unwrap()
+            .energy,
+        15.0
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::Tab);
+    app.update();
+    assert!(app.world().get::<SpaceshipShield>(spaceship).is_none());
+}
+
+#[test]
+fn destroyed_spaceship_respawns_while_lives_remain() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+
+    app.world_mut().get_mut::<Health>(spaceship).unwrap().value = 0.0;
+    app.update();
+    app.update();
+
+    assert_eq!(game_state(&app), GameState::InGame);
+    assert_eq!(app.world().resource::<Lives>().remaining, 2);
+    let respawned = self::spaceship(&mut app).unwrap();
+    assert_ne!(respawned, spaceship);
+    assert!(app.world().get::<Invulnerable>(respawned).is_some());
+}
+
+#[test]
+fn respawned_spaceship_deals_no_collision_damage_while_invulnerable() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+    app.world_mut().get_mut::<Health>(spaceship).unwrap().value = 0.0;
+    app.update();
+    app.update();
+    let respawned = self::spaceship(&mut app).unwrap();
+    assert!(app.world().get::<Invulnerable>(respawned).is_some());
+
+    let translation = app.world().get::<Transform>(respawned).unwrap().translation;
+    let asteroid = spawn_asteroid_at(&mut app, translation);
+    app.update();
+
+    assert_eq!(app.world().get::<Health>(asteroid).unwrap().value, 50.0);
+}
+
+#[test]
+fn game_over_waits_for_restart_without_lives() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+    app.world_mut().resource_mut::<Lives>().remaining = 1;
+
+    app.world_mut().get_mut::<Health>(spaceship).unwrap().value = 0.0;
+    app.update();
+    app.update();
+    app.update();
+    assert_eq!(game_state(&app), GameState::GameOver);
+    assert!(self::spaceship(&mut app).is_none());
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::InGame);
+    app.update();
+    assert_eq!(game_state(&app), GameState::InGame);
+    assert!(self::spaceship(&mut app).is_some());
+    assert_eq!(app.world().resource::<Lives>().remaining, 3);
+}
+
+#[test]
+fn pausing_keeps_the_run_alive() {
+    let mut app = headless_app();
+    let spaceship = spaceship(&mut app).unwrap();
+
+    app.world_mut()
+        .resource_mut::<NextState<PauseState>>()
+        .set(PauseState::Paused);
+    app.update();
+    app.update();
+
+    assert_eq!(
+        *app.world().resource::<State<PauseState>>().get(),
+        PauseState::Paused
+    );
+    assert_eq!(self::spaceship(&mut app), Some(spaceship));
+}
+
+#[test]
+fn same_seed_reproduces_asteroid_timeline() {
+    let timeline = asteroid_timeline(&mut seeded_headless_app(SEED), 40);
+
+    assert!(!timeline.is_empty());
+    assert_eq!(
+        timeline,
+        asteroid_timeline(&mut seeded_headless_app(SEED), 40)
+    );
+    assert_ne!(
+        timeline,
+        asteroid_timeline(&mut seeded_headless_app(SEED + 1), 40)
+    );
+}
+
+#[test]
+fn simulation_does_not_depend_on_frame_rate() {
+    let asteroid_positions = |frame_millis: u64| {
+        let mut app = headless_app_with_frame_time(SEED, frame_millis);
+        // The same two seconds of game time, split into frames of different lengths.
+        for _ in 0..2000 / frame_millis {
+            app.update();
+        }
+        app.world_mut()
+            .query_filtered::<(Entity, &Transform), With<Asteroid>>()
+            .iter(app.world())
+            .map(|(entity, transform)| (entity, transform.translation))
+            .collect::<Vec<_>>()
+    };
+
+    let positions = asteroid_positions(FRAME_MILLIS);
+    assert!(!positions.is_empty());
+    assert_eq!(positions, asteroid_positions(25));
+}
+
+#[test]
+fn fast_mover_hits_asteroid_between_ticks() {
+    let asteroid_health_after_fly_by = |fast_mover: bool| {
+        let mut app = headless_app();
+        let asteroid = spawn_asteroid_at(&mut app, Vec3::new(40.0, 0.0, 40.0));
+        // 20 units per tick at 60 Hz, so the missile is never within reach at the end of a tick.
+        let missile = spawn_missile_at(
+            &mut app,
+            Vec3::new(29.0, 0.0, 40.0),
+            Vec3::new(1200.0, 0.0, 0.0),
+        );
+        if fast_mover {
+            app.world_mut()
+                .entity_mut(missile)
+                .insert(FastMover::default());
+        }
+
+        app.update();
+        app.world().get::<Health>(asteroid).unwrap().value
+    };
+
+    assert_eq!(asteroid_health_after_fly_by(false), 50.0);
+    assert_eq!(asteroid_health_after_fly_by(true), 40.0);
+}
+
+#[test]
+fn held_fire_respects_weapon_cooldown() {
+    let mut app = headless_app();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Space);
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let missile_count = app
+        .world_mut()
+        .query_filtered::<(), With<SpaceshipMissile>>()
+        .iter(app.world())
+        .count();
+    assert!((1..=5).contains(&missile_count));
+}
+
+#[test]
+fn rebound_fire_action_follows_new_binding() {
+    let mut app = headless_app();
+    app.insert_resource(InputBindings {
+        deadzone: 0.0,
+        bindings: vec![Binding::new(Action::Fire, InputSource::Key(KeyCode::KeyF))],
+    });
+
+    let missile_count = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<(), With<SpaceshipMissile>>()
+            .iter(app.world())
+            .count()
+    };
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::Space);
+    app.update();
+    assert_eq!(missile_count(&mut app), 0);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyF);
+    app.update();
+    assert_eq!(missile_count(&mut app), 1);
+}
+
+fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key, state: ButtonState) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+#[test]
+fn tap_between_simulation_ticks_still_fires() {
+    let mut app = headless_app();
+    let missile_count = |app: &mut App| {
+        app.world_mut()
+            .query_filtered::<(), With<SpaceshipMissile>>()
+            .iter(app.world())
+            .count()
+    };
+
+    // Stopped virtual time runs no ticks, like a long hit-stop.
+    app.world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_relative_speed(0.0);
+    send_key(&mut app, KeyCode::Space, Key::Space, ButtonState::Pressed);
+    app.update();
+    send_key(&mut app, KeyCode::Space, Key::Space, ButtonState::Released);
+    app.update();
+    assert_eq!(missile_count(&mut app), 0);
+
+    app.world_mut()
+        .resource_mut::<Time<Virtual>>()
+        .set_relative_speed(1.0);
+    app.update();
+    assert_eq!(missile_count(&mut app), 1);
+}
+
+#[test]
+fn out_of_range_deadzone_in_bindings_file_is_clamped() {
+    let path = std::env::temp_dir().join(format!(
+        "bevy-spaceship-bindings-{}.ron",
+        std::process::id()
+    ));
+    std::fs::write(&path, "(deadzone: 1.5, bindings: [])").unwrap();
+
+    let mut app = App::new();
+    app.add_plugins(HeadlessGamePlugin.set(RngPlugin { seed: Some(SEED) }))
+        .insert_resource(BindingsFile(path.clone()));
+    app.update();
+    std::fs::remove_file(&path).unwrap();
+
+    let deadzone = app.world().resource::<InputBindings>().deadzone;
+    assert!((0.0..1.0).contains(&deadzone));
+}
+
+#[test]
+fn newtonian_flight_keeps_momentum_after_thrust() {
+    let mut app = headless_app();
+    app.world_mut()
+        .resource_mut::<GameConfig>()
+        .spaceship
+        .flight_model = FlightModel::Newtonian;
+    let spaceship = spaceship(&mut app).unwrap();
+    let speed = |app: &App| {
+        app.world()
+            .get::<Velocity>(spaceship)
+            .unwrap()
+            .value
+            .length()
+    };
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyW);
+    for _ in 0..30 {
+        app.update();
+    }
+    let max_speed = app
+        .world()
+        .resource::<GameConfig>()
+        .spaceship
+        .newtonian
+        .max_speed;
+    let thrust_speed = speed(&app);
+    assert!(0.0 < thrust_speed && thrust_speed <= max_speed + f32::EPSILON);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KeyCode::KeyW);
+    app.update();
+    app.update();
+    let coasting_speed = speed(&app);
+    assert!(0.0 < coasting_speed && coasting_speed < thrust_speed);
+}
+
+#[test]
+fn destroyed_asteroid_splits_into_fragments() {
+    let mut app = headless_app();
+    let asteroid = spawn_collider_at(&mut app, Vec3::new(30.0, 0.0, 30.0), Vec3::X);
+    app.world_mut().entity_mut(asteroid).insert((
+        Asteroid::new(3),
+        ASTEROID_LAYERS,
+        Health::new(0.0),
+    ));
+    app.update();
+
+    let fragments: Vec<(u32, f32, f32)> = app
+        .world_mut()
+        .query::<(&Asteroid, &Health, &Collider)>()
+        .iter(app.world())
+        .filter(|(asteroid, _, _)| asteroid.size == 2)
+        .map(|(asteroid, health, collider)| {
+            (
+                asteroid.size,
+                health.value,
+                collider.shape.bounding_radius(),
+            )
+        })
+        .collect();
+    assert!((2..=3).contains(&fragments.len()));
+    for (_, health, radius) in fragments {
+        assert!(health < 50.0);
+        assert!(radius < 1.0);
+    }
+}
+
+#[test]
+fn missile_kill_awards_score() {
+    let mut app = headless_app();
+    let translation = Vec3::new(30.0, 0.0, 30.0);
+    let asteroid = spawn_asteroid_at(&mut app, translation);
+    app.world_mut()
+        .entity_mut(asteroid)
+        .insert((Asteroid::new(3), Health::new(10.0)));
+    spawn_missile_at(&mut app, translation, Vec3::ZERO);
+    app.update();
+    app.update();
+
+    let score = app.world().resource::<Score>();
+    assert_eq!(score.value, 20);
+    assert_eq!(score.combo, 1);
+}
+
+#[test]
+fn damage_passes_through_resistances_and_armor() {
+    let mut app = headless_app();
+    let target = app
+        .world_mut()
+        .spawn((
+            Health::new(100.0),
+            Armor::new(5.0),
+            Resistances {
+                explosive: 0.5,
+                ..default()
+            },
+        ))
+        .id();
+    let regenerating = app
+        .world_mut()
+        .spawn(Health::new(100.0).with_regeneration(10.0))
+        .id();
+    app.world_mut()
+        .get_mut::<Health>(regenerating)
+        .unwrap()
+        .value = 99.9;
+
+    app.world_mut().send_event(DamageEvent::new(
+        target,
+        Entity::PLACEHOLDER,
+        40.0,
+        DamageKind::Explosive,
+    ));
+    app.update();
+    assert_eq!(app.world().get::<Health>(target).unwrap().value, 85.0);
+    // Regeneration stops at max health.
+    assert_eq!(
+        app.world().get::<Health>(regenerating).unwrap().value,
+        100.0
+    );
+
+    app.world_mut().send_event(DamageEvent::new(
+        target,
+        Entity::PLACEHOLDER,
+        200.0,
+        DamageKind::Kinetic,
+    ));
+    app.update();
+    let events = app.world().resource::<Events<EntityDestroyed>>();
+    let destroyed: Vec<(Entity, f32)> = events
+        .get_reader()
+        .read(events)
+        .map(|event| (event.entity, event.overkill))
+        .collect();
+    assert_eq!(destroyed, vec![(target, 110.0)]);
+}
+
+#[test]
+fn dying_entity_reports_kind_position_and_killer() {
+    let mut app = headless_app();
+    let translation = Vec3::new(30.0, 0.0, 30.0);
+    let asteroid = spawn_asteroid_at(&mut app, translation);
+    app.world_mut().get_mut::<Health>(asteroid).unwrap().value = 5.0;
+    let missile = spawn_missile_at(&mut app, translation, Vec3::ZERO);
+    app.update();
+
+    let events = app.world().resource::<Events<EntityDied>>();
+    let asteroid_deaths: Vec<(EntityKind, Vec3, Option<Entity>)> = events
+        .get_reader()
+        .read(events)
+        .filter(|event| event.entity == asteroid)
+        .map(|event| (event.kind, event.position, event.killer))
+        .collect();
+    assert_eq!(
+        asteroid_deaths,
+        vec![(EntityKind::Asteroid { size: 1 }, translation, Some(missile))]
+    );
+    assert!(app.world().get_entity(asteroid).is_none());
+}
+
+#[test]
+fn arena_bounds_apply_each_entity_policy() {
+    let mut app = headless_app();
+    let bounds = app.world().resource::<ArenaBounds>();
+    let edge = bounds.half_extents.x;
+    let outer_edge = edge + bounds.margin;
+
+    let spaceship = spaceship(&mut app).unwrap();
+    app.world_mut()
+        .get_mut::<Transform>(spaceship)
+        .unwrap()
+        .translation
+        .x = outer_edge + 0.5;
+    let leaver = app
+        .world_mut()
+        .spawn((
+            BoundsPolicy::Despawn,
+            Transform::from_xyz(outer_edge + 0.5, 0.0, 0.0),
+        ))
+        .id();
+    let bouncer = app
+        .world_mut()
+        .spawn((
+            BoundsPolicy::Bounce,
+            Transform::from_xyz(edge - 0.1, 0.0, 0.0),
+            Velocity::new(Vec3::new(5.0, 0.0, 0.0)),
+        ))
+        .id();
+    app.update();
+
+    let spaceship_x = app
+        .world()
+        .get::<Transform>(spaceship)
+        .unwrap()
+        .translation
+        .x;
+    assert!((-outer_edge..0.0).contains(&spaceship_x));
+    assert!(app.world().get_entity(leaver).is_none());
+    assert_eq!(
+        app.world().get::<Velocity>(bouncer).unwrap().value,
+        Vec3::new(-5.0, 0.0, 0.0)
+    );
+    assert!(app.world().get::<Transform>(bouncer).unwrap().translation.x <= edge);
+}
+
+#[test]
+fn waves_advance_over_time_and_reset_on_restart() {
+    let mut app = headless_app();
+    assert_eq!(app.world().resource::<Wave>().number, 1);
+
+    app.world_mut()
+        .resource_mut::<GameConfig>()
+        .asteroid
+        .wave_seconds = 0.5;
+    for _ in 0..12 {
+        app.update();
+    }
+    assert_eq!(app.world().resource::<Wave>().number, 3);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::GameOver);
+    app.update();
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::InGame);
+    app.update();
+    assert_eq!(app.world().resource::<Wave>().number, 1);
+}
+
+#[test]
+fn config_seed_applies_without_explicit_seed() {
+    let mut app = App::new();
+    app.add_plugins(HeadlessGamePlugin.set(RngPlugin { seed: None }));
+    app.world_mut().resource_mut::<GameConfig>().seed = Some(SEED);
+    app.update();
+    assert_eq!(app.world().resource::<GameRng>().seed(), SEED);
+
+    let mut app = App::new();
+    app.add_plugins(HeadlessGamePlugin.set(RngPlugin {
+        seed: Some(SEED + 1),
+    }));
+    app.world_mut().resource_mut::<GameConfig>().seed = Some(SEED);
+    app.update();
+    assert_eq!(app.world().resource::<GameRng>().seed(), SEED + 1);
+}
+
+fn headless_app_with<M>(plugins: impl Plugins<M>) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        HeadlessGamePlugin.set(RngPlugin { seed: Some(SEED) }),
+        plugins,
+    ))
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        FRAME_MILLIS,
+    )));
+    app.update();
+    app
+}
+
+fn camera_mode(app: &mut App) -> CameraMode {
+    app.world_mut()
+        .query::<&CameraController>()
+        .single(app.world())
+        .mode
+}
+
+#[test]
+fn cycle_camera_action_advances_camera_mode() {
+    let mut app = headless_app_with(CameraPlugin);
+    assert_eq!(camera_mode(&mut app), CameraMode::TopDown);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::KeyC);
+    app.update();
+
+    assert_eq!(camera_mode(&mut app), CameraMode::Follow);
+}
+
+#[test]
+fn follow_camera_moves_towards_off_center_spaceship() {
+    let mut app = headless_app_with(CameraPlugin);
+    app.world_mut().resource_mut::<GameConfig>().camera.mode = CameraMode::Follow;
+    let spaceship = spaceship(&mut app).unwrap();
+    let target = Vec3::new(15.0, 0.0, -20.0);
+    app.world_mut()
+        .get_mut::<Transform>(spaceship)
+        .unwrap()
+        .translation = target;
+
+    for _ in 0..20 {
+        app.update();
+    }
+
+    let camera = app
+        .world_mut()
+        .query_filtered::<&Transform, With<Camera>>()
+        .single(app.world())
+        .translation;
+    let spaceship = app.world().get::<Transform>(spaceship).unwrap().translation;
+    assert!(spaceship.x > 10.0 && spaceship.z < -15.0);
+    assert!((camera.x - spaceship.x).abs() < 1.0);
+    assert!((camera.z - spaceship.z).abs() < 1.0);
+}
+
+#[test]
+fn camera_trauma_clamps_and_decays_in_real_time() {
+    let mut app = headless_app_with(CameraPlugin);
+    let mut shake = app.world_mut().resource_mut::<CameraShake>();
+    shake.add_trauma(0.7);
+    shake.add_trauma(0.7);
+    assert_eq!(shake.trauma(), 1.0);
+
+    app.update();
+
+    let trauma_decay = app.world().resource::<GameConfig>().camera.trauma_decay;
+    let expected = 1.0 - trauma_decay * FRAME_MILLIS as f32 / 1000.0;
+    let trauma = app.world().resource::<CameraShake>().trauma();
+    assert!((trauma - expected).abs() < 1e-4);
+}
+
+fn hit_spaceship(app: &mut App, fraction: f32) {
+    let spaceship = spaceship(app).unwrap();
+    let mut health = app.world_mut().get_mut::<Health>(spaceship).unwrap();
+    health.value -= health.max * fraction;
+}
+
+fn relative_speed(app: &App) -> f32 {
+    app.world().resource::<Time<Virtual>>().relative_speed()
+}
+
+#[test]
+fn big_hit_slows_time_until_hit_stop_ends() {
+    let mut app = headless_app_with((CameraPlugin, FeedbackPlugin));
+    let hit_stop = {
+        let mut config = app.world_mut().resource_mut::<GameConfig>();
+        config.feedback.hit_stop.seconds = 0.25;
+        config.feedback.hit_stop.clone()
+    };
+
+    hit_spaceship(&mut app, hit_stop.min_damage_fraction);
+    app.update();
+    assert_eq!(relative_speed(&app), hit_stop.time_scale);
+
+    app.update();
+    assert_eq!(relative_speed(&app), hit_stop.time_scale);
+
+    app.update();
+    assert_eq!(relative_speed(&app), 1.0);
+}
+
+#[test]
+fn disabled_hit_stop_leaves_time_alone() {
+    let mut app = headless_app_with((CameraPlugin, FeedbackPlugin));
+    let min_damage_fraction = {
+        let mut config = app.world_mut().resource_mut::<GameConfig>();
+        config.feedback.hit_stop.enabled = false;
+        config.feedback.hit_stop.min_damage_fraction
+    };
+
+    hit_spaceship(&mut app, min_damage_fraction);
+    for _ in 0..3 {
+        app.update();
+        assert_eq!(relative_speed(&app), 1.0);
+    }
+}
+
+fn world_health_bars(app: &mut App, target: Entity) -> usize {
+    app.world_mut()
+        .query::<&WorldHealthBar>()
+        .iter(app.world())
+        .filter(|bar| bar.target == target)
+        .count()
+}
+
+#[test]
+fn world_health_bar_appears_on_damage_and_fades_at_full_health() {
+    let mut app = headless_app_with((CameraPlugin, HealthBarPlugin));
+    let target = app
+        .world_mut()
+        .spawn((Health::new(50.0), TransformBundle::default()))
+        .id();
+    app.update();
+    assert_eq!(world_health_bars(&mut app, target), 0);
+
+    app.world_mut().get_mut::<Health>(target).unwrap().value = 20.0;
+    app.update();
+    assert_eq!(world_health_bars(&mut app, target), 1);
+
+    app.world_mut().get_mut::<Health>(target).unwrap().value = 50.0;
+    let fade_seconds = app
+        .world()
+        .resource::<GameConfig>()
+        .hud
+        .health_bar_fade_seconds;
+    let fade_frames = (fade_seconds * 1000.0 / FRAME_MILLIS as f32).round() as usize;
+    for _ in 0..fade_frames / 2 {
+        app.update();
+    }
+    assert_eq!(world_health_bars(&mut app, target), 1);
+    for _ in 0..fade_frames {
+        app.update();
+    }
+    assert_eq!(world_health_bars(&mut app, target), 0);
+}
+
+#[test]
+fn disabled_world_health_bars_are_not_spawned() {
+    let mut app = headless_app_with((CameraPlugin, HealthBarPlugin));
+    app.world_mut()
+        .resource_mut::<GameConfig>()
+        .hud
+        .world_health_bars = false;
+    let target = app
+        .world_mut()
+        .spawn((Health::new(50.0), TransformBundle::default()))
+        .id();
+
+    app.world_mut().get_mut::<Health>(target).unwrap().value = 20.0;
+    app.update();
+    assert_eq!(world_health_bars(&mut app, target), 0);
+}
+
+fn hud_texts(app: &mut App) -> Vec<String> {
+    app.world_mut()
+        .query::<&Text>()
+        .iter(app.world())
+        .flat_map(|text| text.sections.iter().map(|section| section.value.clone()))
+        .collect()
+}
+
+#[test]
+fn hud_shows_bars_and_run_status_when_run_starts() {
+    let mut app = headless_app_with(HudPlugin);
+    let lives = app.world().resource::<Lives>().remaining;
+
+    let texts = hud_texts(&mut app);
+    for expected in [
+        "Hull".to_string(),
+        "Shield".to_string(),
+        "Heat".to_string(),
+        "Score: 0".to_string(),
+        format!("Lives: {}", lives),
+        "Wave 1".to_string(),
+    ] {
+        assert!(
+            texts.contains(&expected),
+            "missing {:?} in {:?}",
+            expected,
+            texts
+        );
+    }
+
+    app.world_mut().resource_mut::<Wave>().number = 4;
+    app.update();
+    assert!(hud_texts(&mut app).contains(&"Wave 4".to_string()));
+}
+
+#[test]
+fn hud_health_bar_follows_spaceship_health() {
+    let mut app = headless_app_with(HudPlugin);
+    // The bar row holds the "Hull" label and the bar background, whose only child is the fill.
+    let health_bar_width = |app: &mut App| {
+        let world = app.world_mut();
+        let label = world
+            .query::<(&Text, &Parent)>()
+            .iter(world)
+            .find(|(text, _)| text.sections[0].value == "Hull")
+            .map(|(_, parent)| parent.get())
+            .unwrap();
+        let background = world.get::<Children>(label).unwrap()[1];
+        let fill = world.get::<Children>(background).unwrap()[0];
+        world.get::<Style>(fill).unwrap().width
+    };
+    assert_eq!(health_bar_width(&mut app), Val::Percent(100.0));
+
+    hit_spaceship(&mut app, 0.5);
+    app.update();
+
+    let spaceship = spaceship(&mut app).unwrap();
+    let health = app.world().get::<Health>(spaceship).unwrap();
+    let expected = Val::Percent(health.value / health.max * 100.0);
+    assert!(health.value < health.max);
+    assert_eq!(health_bar_width(&mut app), expected);
+}